@@ -0,0 +1,16 @@
+import Receiver from "./module4";
+
+const LABELS = translate({
+  a: {
+    b: {
+      bird: "i18n.bird",
+    },
+    c: {
+      cat: "i18n.cat",
+    },
+  },
+});
+
+function Passer() {
+  return <Receiver labels={LABELS.a} />;
+}
@@ -0,0 +1,3 @@
+export default function Receiver({ labels }) {
+  return <p>{labels.b.bird}</p>;
+}
@@ -0,0 +1,708 @@
+use crate::core::{
+    self, DefaultText, KeyCall, LazyLabel, ModuleContext, ProjectSummaries, TranslationUsage,
+    MAX_SUMMARY_ROUNDS,
+};
+use crate::diagnostic::LocatedDiagnostic;
+use crate::extractor::{
+    Extractor, ExtractorContext, I18nextExtractor, LabelsExtractor, ReactIntlExtractor,
+};
+use crate::location::Location;
+use crate::symbol::{
+    get_display_name_for_module, Symbol, SymbolKind, ANONYMOUS_DEFAULT_EXPORT_NAME,
+    MODULE_SIDE_EFFECTS_NAME,
+};
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+use swc_core::{
+    common::{
+        comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS,
+    },
+    ecma::{ast::*, transforms::base::resolver, visit::FoldWith},
+};
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalyzerConfig {
+    // Also attribute the keys read by the components receiving a part of the
+    // LABELS as props, see `core::collect_translation_following_props`.
+    pub follow_props: bool,
+    // Report the members of classes and objects as their own symbols, like
+    // `Foo#handleClick`, see `core::Options::qualified_symbols`.
+    pub qualified_symbols: bool,
+    // Also extract the keys of react-i18next and i18next `t` calls, prefixed
+    // with their namespaces like `ns:key`, see `extractor::I18nextExtractor`.
+    pub i18next: bool,
+    // Also extract the message ids of react-intl, `defineMessages`,
+    // `<FormattedMessage id="..." />` and `intl.formatMessage`, see
+    // `extractor::ReactIntlExtractor`.
+    pub react_intl: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyUsage {
+    pub key: String,
+    // set for `['<i18n key>', 'lazy']`
+    pub lazy: Option<LazyLabel>,
+    // empty when the key is read through the props passed by another module
+    pub locations: Vec<Location>,
+    // the calls of the key with their parameters, like `LABELS.desc({ date })`
+    pub calls: Vec<KeyCall>,
+    // the default texts written in the code, like the children of `<Trans>`
+    pub default_texts: Vec<DefaultText>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolUsage {
+    pub name: String,
+    pub display_name: String,
+    pub kind: SymbolKind,
+    pub exported_as: Vec<String>,
+    // where the symbol is declared, unknown for the readers of other modules
+    pub location: Option<Location>,
+    // sorted by key
+    pub keys: Vec<KeyUsage>,
+}
+
+impl SymbolUsage {
+    // `symbol` is unknown for the readers of other modules, which are the
+    // functions receiving a part of the LABELS, and for the extractors which
+    // don't report their symbols.
+    fn new(module_path: &str, name: &str, symbol: Option<&Symbol>) -> Self {
+        match symbol {
+            Some(symbol) => Self {
+                name: symbol.name.clone(),
+                display_name: symbol.display_name.clone(),
+                kind: symbol.kind,
+                exported_as: symbol.exported_as.clone(),
+                location: None,
+                keys: vec![],
+            },
+            None => {
+                let kind = match name {
+                    ANONYMOUS_DEFAULT_EXPORT_NAME => SymbolKind::Default,
+                    MODULE_SIDE_EFFECTS_NAME => SymbolKind::ModuleSideEffect,
+                    _ => SymbolKind::Function,
+                };
+                Self {
+                    name: name.to_string(),
+                    display_name: match kind {
+                        SymbolKind::Default => get_display_name_for_module(module_path),
+                        _ => name.to_string(),
+                    },
+                    kind,
+                    exported_as: vec![],
+                    location: None,
+                    keys: vec![],
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleAnalysis {
+    pub path: String,
+    // the symbols using translation keys, sorted by name
+    pub symbols: Vec<SymbolUsage>,
+    // the problems found in the module, sorted by location
+    pub diagnostics: Vec<LocatedDiagnostic>,
+}
+
+impl ModuleAnalysis {
+    fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            symbols: vec![],
+            diagnostics: vec![],
+        }
+    }
+
+    fn get_symbol_mut(&mut self, name: &str) -> Option<&mut SymbolUsage> {
+        self.symbols.iter_mut().find(|symbol| symbol.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectAnalysis {
+    // the modules using translation keys, sorted by path
+    pub modules: Vec<ModuleAnalysis>,
+}
+
+impl ProjectAnalysis {
+    // translation key -> module path -> the display names of the symbols using
+    // the key, the input of the dependency tracker
+    pub fn get_key_usage(&self) -> BTreeMap<String, BTreeMap<String, BTreeSet<String>>> {
+        let mut key_usage: BTreeMap<String, BTreeMap<String, BTreeSet<String>>> = BTreeMap::new();
+        for module in &self.modules {
+            for symbol in &module.symbols {
+                for key in &symbol.keys {
+                    key_usage
+                        .entry(key.key.clone())
+                        .or_default()
+                        .entry(module.path.clone())
+                        .or_default()
+                        .insert(symbol.display_name.clone());
+                }
+            }
+        }
+        key_usage
+    }
+}
+
+pub struct Analyzer {
+    config: AnalyzerConfig,
+    extractors: Vec<Box<dyn Extractor>>,
+}
+
+impl Analyzer {
+    pub fn new(config: AnalyzerConfig) -> Self {
+        let mut extractors: Vec<Box<dyn Extractor>> = vec![Box::new(LabelsExtractor)];
+        if config.i18next {
+            extractors.push(Box::new(I18nextExtractor));
+        }
+        if config.react_intl {
+            extractors.push(Box::new(ReactIntlExtractor));
+        }
+        Self { config, extractors }
+    }
+
+    // Support another translation API, the usages of all the extractors are
+    // merged.
+    pub fn add_extractor(&mut self, extractor: impl Extractor + 'static) {
+        self.extractors.push(Box::new(extractor));
+    }
+
+    // Analyze a single module, the imports can't be followed.
+    pub fn analyze_source(&self, path: &str, text: &str) -> anyhow::Result<ModuleAnalysis> {
+        let (module, source_map, comments) = parse_source(path, text)?;
+        let resolve_import = |_: &str| None;
+        let context = ModuleContext {
+            module_path: path,
+            summaries: &ProjectSummaries::new(),
+            resolve_import: &resolve_import,
+        };
+        let translation_usage =
+            self.collect_translation(&module, &context, &source_map, &comments)?;
+        Ok(get_module_analysis(path, &translation_usage, &source_map))
+    }
+
+    // Analyze the js, jsx, ts and tsx modules under `root`, tests excluded.
+    pub fn analyze_project(&self, root: &Path) -> anyhow::Result<ProjectAnalysis> {
+        let mut modules = vec![];
+        for path in collect_all_paths(root)? {
+            let path_str = path.to_str().context("&PathBuf -> &str")?.to_string();
+            let text =
+                std::fs::read_to_string(&path).context(format!("failed to load {:?}", path))?;
+            let (module, source_map, comments) = parse_source(&path_str, &text)?;
+            modules.push((path_str, module, source_map, comments));
+        }
+        let summaries = match self.config.follow_props {
+            true => summarize_modules(&modules),
+            false => ProjectSummaries::new(),
+        };
+
+        let mut analyses: BTreeMap<String, ModuleAnalysis> = BTreeMap::new();
+        let mut external = vec![];
+        for (path_str, module, source_map, comments) in &modules {
+            let resolve_import = |source: &str| resolve_import(Path::new(path_str), source);
+            let context = ModuleContext {
+                module_path: path_str,
+                summaries: &summaries,
+                resolve_import: &resolve_import,
+            };
+            let translation_usage =
+                self.collect_translation(module, &context, source_map, comments)?;
+            let analysis = get_module_analysis(path_str, &translation_usage, source_map);
+            if !analysis.symbols.is_empty() || !analysis.diagnostics.is_empty() {
+                analyses.insert(path_str.clone(), analysis);
+            }
+            external.push((translation_usage.external, translation_usage.lazy));
+        }
+
+        // the keys read by the symbols of other modules
+        for (usage, lazy) in external {
+            for (symbol_ref, translation_keys) in usage {
+                let analysis = analyses
+                    .entry(symbol_ref.module.clone())
+                    .or_insert_with(|| ModuleAnalysis::new(&symbol_ref.module));
+                if analysis.get_symbol_mut(&symbol_ref.symbol).is_none() {
+                    analysis.symbols.push(SymbolUsage::new(
+                        &symbol_ref.module,
+                        &symbol_ref.symbol,
+                        None,
+                    ));
+                    analysis.symbols.sort_by(|a, b| a.name.cmp(&b.name));
+                }
+                let symbol = analysis.get_symbol_mut(&symbol_ref.symbol).unwrap();
+                for translation_key in translation_keys {
+                    if symbol.keys.iter().any(|key| key.key == translation_key) {
+                        continue;
+                    }
+                    symbol.keys.push(KeyUsage {
+                        lazy: lazy.get(&translation_key).cloned(),
+                        key: translation_key,
+                        locations: vec![],
+                        calls: vec![],
+                        default_texts: vec![],
+                    });
+                }
+                symbol.keys.sort_by(|a, b| a.key.cmp(&b.key));
+            }
+        }
+
+        Ok(ProjectAnalysis {
+            modules: analyses.into_values().collect(),
+        })
+    }
+
+    fn collect_translation(
+        &self,
+        module: &Module,
+        context: &ModuleContext,
+        source_map: &SourceMap,
+        comments: &SingleThreadedComments,
+    ) -> anyhow::Result<TranslationUsage> {
+        let context = ExtractorContext {
+            module_context: context,
+            config: self.config,
+            source_map,
+            comments,
+        };
+        let mut translation_usage = TranslationUsage::new();
+        for extractor in &self.extractors {
+            translation_usage.merge(extractor.extract(module, &context)?);
+        }
+        Ok(translation_usage)
+    }
+}
+
+fn get_module_analysis(
+    path: &str,
+    translation_usage: &TranslationUsage,
+    source_map: &SourceMap,
+) -> ModuleAnalysis {
+    let mut analysis = ModuleAnalysis::new(path);
+    for (name, translation_keys) in &translation_usage.data {
+        let mut symbol = SymbolUsage::new(path, name, translation_usage.symbols.get(name));
+        symbol.location = translation_usage
+            .symbols
+            .get(name)
+            .map(|symbol| Location::new(source_map, path, symbol.span));
+        for translation_key in translation_keys {
+            symbol.keys.push(KeyUsage {
+                key: translation_key.clone(),
+                lazy: translation_usage.lazy.get(translation_key).cloned(),
+                locations: translation_usage.get_locations(source_map, path, name, translation_key),
+                calls: translation_usage.get_calls(source_map, path, name, translation_key),
+                default_texts: translation_usage.get_default_texts(name, translation_key),
+            });
+        }
+        symbol.keys.sort_by(|a, b| a.key.cmp(&b.key));
+        analysis.symbols.push(symbol);
+    }
+    analysis.symbols.sort_by(|a, b| a.name.cmp(&b.name));
+    analysis.diagnostics = translation_usage
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.locate(source_map, path))
+        .collect();
+    analysis
+        .diagnostics
+        .sort_by_key(|diagnostic| (diagnostic.location.line, diagnostic.location.column));
+    analysis
+}
+
+// (path, module, source map, comments)
+type ParsedModule = (String, Module, Lrc<SourceMap>, SingleThreadedComments);
+
+// The summaries of the modules depend on the summaries of the modules they
+// import, see `MAX_SUMMARY_ROUNDS`.
+fn summarize_modules(modules: &[ParsedModule]) -> ProjectSummaries {
+    let mut summaries = ProjectSummaries::new();
+    for _ in 0..MAX_SUMMARY_ROUNDS {
+        let mut next = ProjectSummaries::new();
+        for (path_str, module, _, _) in modules {
+            let summary = core::summarize_module(
+                module,
+                &ModuleContext {
+                    module_path: path_str,
+                    summaries: &summaries,
+                    resolve_import: &|source| resolve_import(Path::new(path_str), source),
+                },
+            );
+            next.insert(path_str.to_string(), summary);
+        }
+        if next == summaries {
+            break;
+        }
+        summaries = next;
+    }
+    summaries
+}
+
+fn collect_all_paths(root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let path = root.canonicalize()?;
+    let mut paths = vec![];
+
+    if path.is_dir() {
+        for entry in path.read_dir()?.flatten() {
+            paths.append(&mut collect_all_paths(&entry.path())?);
+        }
+        paths.sort();
+        return Ok(paths);
+    }
+
+    let path_str = path.to_str().context("path to str")?;
+    if (path_str.ends_with(".js")
+        || path_str.ends_with(".cjs")
+        || path_str.ends_with(".jsx")
+        || path_str.ends_with(".ts")
+        || path_str.ends_with(".tsx"))
+        && !path_str.ends_with(".spec.js")
+        && !path_str.ends_with(".spec.cjs")
+        && !path_str.ends_with(".spec.jsx")
+        && !path_str.ends_with(".spec.ts")
+        && !path_str.ends_with(".spec.tsx")
+        && !path_str.ends_with(".test.js")
+        && !path_str.ends_with(".test.cjs")
+        && !path_str.ends_with(".test.jsx")
+        && !path_str.ends_with(".test.ts")
+        && !path_str.ends_with(".test.tsx")
+    {
+        paths.push(path.clone())
+    }
+    Ok(paths)
+}
+
+// Only the relative imports are resolved, like `./Bar` to `./Bar.tsx` or
+// `./Bar/index.tsx`, the packages are never part of the project.
+fn resolve_import(importer: &Path, source: &str) -> Option<String> {
+    if !source.starts_with('.') {
+        return None;
+    }
+    let base = importer.parent()?.join(source);
+    let base_str = base.to_str()?;
+    let mut candidates = vec![base.clone()];
+    for extension in ["js", "cjs", "jsx", "ts", "tsx"] {
+        candidates.push(PathBuf::from(format!("{}.{}", base_str, extension)));
+    }
+    for extension in ["js", "cjs", "jsx", "ts", "tsx"] {
+        candidates.push(base.join(format!("index.{}", extension)));
+    }
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.is_file())
+        .find_map(|candidate| Some(candidate.canonicalize().ok()?.to_str()?.to_string()))
+}
+
+// The source map locates the spans of the module, see `Location`.
+fn parse_source(
+    path: &str,
+    text: &str,
+) -> anyhow::Result<(Module, Lrc<SourceMap>, SingleThreadedComments)> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let comments = SingleThreadedComments::default();
+    let fm = cm.new_source_file(
+        Lrc::new(FileName::Real(PathBuf::from(path))),
+        text.to_string(),
+    );
+
+    let module = match parse_file_as_module(
+        &fm,
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            decorators: true,
+            no_early_errors: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        Some(&comments),
+        &mut Vec::new(),
+    ) {
+        Ok(v) => v,
+        // We are not testing parser
+        Err(..) => bail!("failed to parse {:?}", path),
+    };
+
+    // This is how swc manages identifiers. ref: https://rustdoc.swc.rs/swc_ecma_transforms/fn.resolver.html
+    let module = GLOBALS.set(&Globals::new(), move || {
+        module.fold_with(&mut resolver(Mark::new(), Mark::new(), true))
+    });
+
+    Ok((module, cm, comments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+    use swc_core::ecma::visit::{Visit, VisitWith};
+
+    #[test]
+    fn analyze_source() {
+        let analyzer = Analyzer::new(AnalyzerConfig::default());
+        let analysis = analyzer
+            .analyze_source(
+                "src/Bird/index.tsx",
+                r#"const LABELS = translate({
+    bird: "i18n.bird",
+    desc: ["i18n.bird.desc", "lazy"],
+})
+export default () => <div title={LABELS.bird}>{LABELS.desc({ date })}</div>
+"#,
+            )
+            .unwrap();
+        assert_eq!(analysis.path, "src/Bird/index.tsx");
+        assert_eq!(analysis.symbols.len(), 1);
+        let symbol = &analysis.symbols[0];
+        assert_eq!(symbol.name, "<default>");
+        assert_eq!(symbol.display_name, "Bird");
+        assert_eq!(symbol.kind, SymbolKind::Default);
+        assert_eq!(symbol.exported_as, ["default"]);
+        assert_eq!(
+            symbol
+                .location
+                .as_ref()
+                .map(|location| (location.line, location.column)),
+            Some((5, 16))
+        );
+        assert_eq!(
+            symbol.keys,
+            [
+                KeyUsage {
+                    key: "i18n.bird".to_string(),
+                    lazy: None,
+                    locations: vec![Location {
+                        file: "src/Bird/index.tsx".to_string(),
+                        line: 5,
+                        column: 34,
+                        text: "LABELS.bird".to_string(),
+                    }],
+                    calls: vec![],
+                    default_texts: vec![],
+                },
+                KeyUsage {
+                    key: "i18n.bird.desc".to_string(),
+                    lazy: Some(LazyLabel::new("i18n.bird.desc".to_string())),
+                    locations: vec![Location {
+                        file: "src/Bird/index.tsx".to_string(),
+                        line: 5,
+                        column: 48,
+                        text: "LABELS.desc".to_string(),
+                    }],
+                    calls: vec![KeyCall {
+                        location: Location {
+                            file: "src/Bird/index.tsx".to_string(),
+                            line: 5,
+                            column: 48,
+                            text: "LABELS.desc({ date })".to_string(),
+                        },
+                        params: Some(vec!["date".to_string()]),
+                    }],
+                    default_texts: vec![],
+                },
+            ]
+        );
+
+        // serializable for the other tools
+        let json = serde_json::to_string(&analysis).unwrap();
+        assert!(json.contains(r#""kind":"default""#));
+        assert_eq!(
+            serde_json::from_str::<ModuleAnalysis>(&json).unwrap(),
+            analysis
+        );
+    }
+
+    #[test]
+    fn qualified_symbols_across_extractors() {
+        let analyzer = Analyzer::new(AnalyzerConfig {
+            qualified_symbols: true,
+            i18next: true,
+            react_intl: true,
+            ..Default::default()
+        });
+        let analysis = analyzer
+            .analyze_source(
+                "src/Foo.tsx",
+                r#"const LABELS = translate({ title: "i18n.title" })
+export class Foo extends Component {
+    render() {
+        return (
+            <div title={LABELS.title}>
+                {i18n.t("i18next.title")}
+                <FormattedMessage id="intl.title" />
+            </div>
+        )
+    }
+}
+"#,
+            )
+            .unwrap();
+        assert_eq!(analysis.symbols.len(), 1);
+        let symbol = &analysis.symbols[0];
+        assert_eq!(symbol.name, "Foo#render");
+        assert_eq!(
+            symbol
+                .keys
+                .iter()
+                .map(|key_usage| key_usage.key.as_str())
+                .collect::<Vec<_>>(),
+            ["i18n.title", "i18next.title", "intl.title"]
+        );
+    }
+
+    #[test]
+    fn malformed_labels() {
+        let analyzer = Analyzer::new(AnalyzerConfig::default());
+        assert!(analyzer
+            .analyze_source("src/Foo.tsx", "const LABELS = translate()")
+            .is_err());
+        assert!(analyzer
+            .analyze_source("src/Foo.tsx", "const LABELS = translate({ bird: 1 })")
+            .is_err());
+        assert!(analyzer
+            .analyze_source(
+                "src/Foo.tsx",
+                "export const LABELS = translate({ [a]: \"i18n.a\", b: \"i18n.b\" })"
+            )
+            .is_err());
+    }
+
+    // `t("<i18n key>")` anywhere in the module, used by `<module side effects>`
+    struct TExtractor;
+
+    impl Extractor for TExtractor {
+        fn extract(
+            &self,
+            module: &Module,
+            _: &ExtractorContext,
+        ) -> anyhow::Result<TranslationUsage> {
+            struct TVisitor(TranslationUsage);
+            impl Visit for TVisitor {
+                fn visit_call_expr(&mut self, node: &CallExpr) {
+                    if let (Callee::Expr(callee), Some(arg)) = (&node.callee, node.args.first()) {
+                        match (&**callee, &*arg.expr) {
+                            (Expr::Ident(ident), Expr::Lit(Lit::Str(str))) if ident.sym == "t" => {
+                                let key = str.value.to_string();
+                                self.0.extend(HashMap::from([(
+                                    MODULE_SIDE_EFFECTS_NAME.to_string(),
+                                    HashSet::from([key.clone()]),
+                                )]));
+                                self.0
+                                    .occurrences
+                                    .entry(MODULE_SIDE_EFFECTS_NAME.to_string())
+                                    .or_default()
+                                    .entry(key)
+                                    .or_default()
+                                    .push(node.span);
+                            }
+                            _ => (),
+                        }
+                    }
+                    node.visit_children_with(self);
+                }
+            }
+            let mut visitor = TVisitor(TranslationUsage::new());
+            module.visit_with(&mut visitor);
+            Ok(visitor.0)
+        }
+    }
+
+    #[test]
+    fn custom_extractors() {
+        let mut analyzer = Analyzer::new(AnalyzerConfig::default());
+        analyzer.add_extractor(TExtractor);
+        let analysis = analyzer
+            .analyze_source(
+                "Foo.js",
+                r#"const LABELS = translate({
+    bird: "i18n.bird",
+})
+const Foo = () => LABELS.bird
+toast(t("i18n.saved"))
+"#,
+            )
+            .unwrap();
+        assert_eq!(analysis.symbols[0].kind, SymbolKind::ModuleSideEffect);
+        let keys = analysis
+            .symbols
+            .iter()
+            .map(|symbol| {
+                let key = &symbol.keys[0];
+                (
+                    symbol.display_name.as_str(),
+                    key.key.as_str(),
+                    key.locations[0].text.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [
+                ("<module side effects>", "i18n.saved", r#"t("i18n.saved")"#),
+                ("Foo", "i18n.bird", "LABELS.bird"),
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_project_following_props() {
+        let analyzer = Analyzer::new(AnalyzerConfig {
+            follow_props: true,
+            ..Default::default()
+        });
+        let key_usage = analyzer
+            .analyze_project(Path::new("./fixtures"))
+            .unwrap()
+            .get_key_usage();
+        let root = Path::new("./fixtures").canonicalize().unwrap();
+        let module = |name: &str| root.join(name).to_str().unwrap().to_string();
+        let symbols = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        assert_eq!(
+            key_usage.get("i18n.bird").unwrap(),
+            &BTreeMap::from([
+                (module("module1.js"), symbols(&["A", "B"])),
+                (module("module2.js"), symbols(&["Bar", "Foo"])),
+                (module("module3.js"), symbols(&["Passer"])),
+                (module("module4.js"), symbols(&["Receiver"])),
+            ])
+        );
+        assert_eq!(
+            key_usage.get("i18n.cat").unwrap(),
+            &BTreeMap::from([
+                (module("module1.js"), symbols(&["A", "B", "C"])),
+                (module("module3.js"), symbols(&["Passer"])),
+            ])
+        );
+    }
+
+    #[test]
+    fn commonjs_paths() {
+        let root = std::env::temp_dir().join(format!("commonjs_paths_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("labels")).unwrap();
+        for name in [
+            "labels/index.cjs",
+            "Foo.cjs",
+            "Foo.test.cjs",
+            "Foo.spec.cjs",
+        ] {
+            std::fs::write(root.join(name), "").unwrap();
+        }
+        let root = root.canonicalize().unwrap();
+        let paths = collect_all_paths(&root);
+        let resolved = resolve_import(&root.join("Foo.cjs"), "./labels");
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            paths.unwrap(),
+            [root.join("Foo.cjs"), root.join("labels/index.cjs")]
+        );
+        assert_eq!(
+            resolved.unwrap(),
+            root.join("labels/index.cjs").to_str().unwrap()
+        );
+    }
+}
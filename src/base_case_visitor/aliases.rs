@@ -0,0 +1,146 @@
+use super::labels::{LabelPath, PathSegment};
+use std::collections::{HashMap, HashSet};
+use swc_core::ecma::ast::Id;
+
+// Where a label path starts, the `LABELS` itself or a parameter of the top
+// level function being visited, which receives a part of the LABELS from its
+// callers, e.g. `const Bar = ({ labels }) => labels.b.bird` is
+// `Param(0)` with `[Prop("labels"), Prop("b"), Prop("bird")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelRoot {
+    Labels,
+    Param(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RootedPath {
+    pub root: LabelRoot,
+    pub path: LabelPath,
+}
+
+impl RootedPath {
+    pub fn labels() -> Self {
+        Self {
+            root: LabelRoot::Labels,
+            path: vec![],
+        }
+    }
+
+    pub fn param(index: usize) -> Self {
+        Self {
+            root: LabelRoot::Param(index),
+            path: vec![],
+        }
+    }
+
+    pub fn join(&self, rest: &[PathSegment]) -> Self {
+        let mut path = self.path.clone();
+        path.extend_from_slice(rest);
+        Self {
+            root: self.root,
+            path,
+        }
+    }
+}
+
+// The parts of the LABELS a binding might hold, `cond ? LABELS.a : LABELS.b`
+// holds both `a` and `b`.
+pub type LabelPaths = HashSet<RootedPath>;
+
+pub fn extend_label_paths(paths: &LabelPaths, segment: PathSegment) -> LabelPaths {
+    paths
+        .iter()
+        .map(|path| path.join(std::slice::from_ref(&segment)))
+        .collect()
+}
+
+// The bindings holding a part of the LABELS at the current program point, like
+// `const L = LABELS.a` or `const { bird } = LABELS`.
+#[derive(Debug, Clone, Default)]
+pub struct Aliases {
+    bindings: HashMap<Id, LabelPaths>,
+}
+
+impl Aliases {
+    pub fn get(&self, id: &Id) -> Option<&LabelPaths> {
+        self.bindings.get(id)
+    }
+
+    // the binding is (re)assigned, forget what it held before
+    pub fn assign(&mut self, id: Id, paths: LabelPaths) {
+        self.bindings.insert(id, paths);
+    }
+
+    pub fn remove(&mut self, id: &Id) {
+        self.bindings.remove(id);
+    }
+
+    // Merge the aliases of two control flow branches, a binding might hold
+    // what it holds in either of them.
+    pub fn join(&mut self, other: Aliases) {
+        for (id, paths) in other.bindings {
+            self.bindings.entry(id).or_default().extend(paths);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::{atoms::Atom, common::SyntaxContext};
+
+    fn id(sym: &str) -> Id {
+        (Atom::new(sym), SyntaxContext::empty())
+    }
+
+    fn path(props: &[&str]) -> RootedPath {
+        let segments: LabelPath = props
+            .iter()
+            .map(|prop| PathSegment::Prop(prop.to_string()))
+            .collect();
+        RootedPath::labels().join(&segments)
+    }
+
+    #[test]
+    fn assign_replaces() {
+        let mut aliases = Aliases::default();
+        aliases.assign(id("L"), HashSet::from([path(&["a"])]));
+        aliases.assign(id("L"), HashSet::from([path(&["b"])]));
+        assert_eq!(
+            aliases.get(&id("L")).unwrap(),
+            &HashSet::from([path(&["b"])])
+        );
+        aliases.remove(&id("L"));
+        assert!(aliases.get(&id("L")).is_none());
+    }
+
+    #[test]
+    fn join_merges() {
+        let mut aliases = Aliases::default();
+        aliases.assign(id("L"), HashSet::from([path(&["a"])]));
+        let mut other = aliases.clone();
+        other.assign(id("L"), HashSet::from([path(&["b"])]));
+        other.assign(id("M"), HashSet::from([path(&["c"])]));
+        aliases.join(other);
+        assert_eq!(
+            aliases.get(&id("L")).unwrap(),
+            &HashSet::from([path(&["a"]), path(&["b"])])
+        );
+        assert_eq!(
+            aliases.get(&id("M")).unwrap(),
+            &HashSet::from([path(&["c"])])
+        );
+    }
+
+    #[test]
+    fn extend() {
+        let paths = HashSet::from([path(&["a"]), path(&["b"])]);
+        assert_eq!(
+            extend_label_paths(&paths, PathSegment::Computed),
+            HashSet::from([
+                path(&["a"]).join(&[PathSegment::Computed]),
+                path(&["b"]).join(&[PathSegment::Computed]),
+            ])
+        );
+    }
+}
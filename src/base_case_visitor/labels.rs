@@ -1,17 +1,80 @@
-use anyhow::{bail, Context};
+use crate::diagnostic::{Diagnostic, Rule};
+use crate::key_pattern::{
+    fold_string_expr, has_literal_wildcard, StringConstants, KEY_PATTERN_WILDCARD,
+};
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
-use swc_core::ecma::ast::*;
+use swc_core::{
+    common::{Span, Spanned},
+    ecma::ast::*,
+};
 
-#[derive(Debug, PartialEq)]
+const LAZY_FORMAT_ERROR: &str =
+    "array lit can only be ['<i18n key>', 'lazy'] or ['<i18n key>', 'lazy', { /* modifiers */ }]";
+
+// `['<i18n key>', 'lazy', { plural, defaultText, description }]`, the translation
+// is a function which should be called with the interpolation parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LazyLabel {
+    pub key: String,
+    pub plural: bool,
+    pub default_text: Option<String>,
+    pub description: Option<String>,
+}
+
+impl LazyLabel {
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            plural: false,
+            default_text: None,
+            description: None,
+        }
+    }
+}
+
+// A call of a label, like `LABELS.desc({ date })`, which is how the lazy labels
+// are used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LabelCall {
+    // the call expression
+    pub span: Span,
+    // the names of the interpolation parameters, sorted, `None` if they can't
+    // be known, like `LABELS.desc(params)`
+    pub params: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum TranslateObjectValue {
     String(String),
+    Lazy(LazyLabel),
     NestedLabels(LABELS),
 }
 
 impl TranslateObjectValue {
+    // the translation key if it's a leaf of the labels tree
+    pub fn get_translation_key(&self) -> Option<&str> {
+        match self {
+            TranslateObjectValue::String(s) => Some(s),
+            TranslateObjectValue::Lazy(lazy_label) => Some(&lazy_label.key),
+            TranslateObjectValue::NestedLabels(_) => None,
+        }
+    }
+
     pub fn get_string(&self) -> anyhow::Result<&str> {
         match self {
             TranslateObjectValue::String(s) => Ok(s),
+            TranslateObjectValue::Lazy(_) => bail!("it's a lazy label"),
+            TranslateObjectValue::NestedLabels(_) => bail!("it's a nested labels"),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn get_lazy(&self) -> anyhow::Result<&LazyLabel> {
+        match self {
+            TranslateObjectValue::String(_) => bail!("it's a string"),
+            TranslateObjectValue::Lazy(lazy_label) => Ok(lazy_label),
             TranslateObjectValue::NestedLabels(_) => bail!("it's a nested labels"),
         }
     }
@@ -19,18 +82,22 @@ impl TranslateObjectValue {
     pub fn get_labels(&self) -> anyhow::Result<&LABELS> {
         match self {
             TranslateObjectValue::String(_) => bail!("it's a string"),
+            TranslateObjectValue::Lazy(_) => bail!("it's a lazy label"),
             TranslateObjectValue::NestedLabels(labels) => Ok(labels),
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+// named after the JS binding it models
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LABELS {
     Object(HashMap<String, TranslateObjectValue>),
 
     // If we found the object has computed keys, just collect all lokalise keys into a vector.
     // Because usually we'll use partial or all of them in the runtime.
-    Computed(HashSet<String>),
+    // It only contains the leaves, `TranslateObjectValue::String` and `TranslateObjectValue::Lazy`.
+    Computed(Vec<TranslateObjectValue>),
 }
 
 impl LABELS {
@@ -41,65 +108,191 @@ impl LABELS {
         }
     }
 
-    pub fn get_computed(&self) -> anyhow::Result<&HashSet<String>> {
+    pub fn get_computed(&self) -> anyhow::Result<HashSet<&str>> {
         match self {
             LABELS::Object(_) => bail!("it's an object"),
-            LABELS::Computed(hash_set) => Ok(hash_set),
+            LABELS::Computed(leaves) => Ok(leaves
+                .iter()
+                .filter_map(|leaf| leaf.get_translation_key())
+                .collect()),
         }
     }
 
-    // follow the path, then collect all the nested keys
+    // all the lazy labels in the tree, keyed by their translation keys
+    pub fn get_lazy_labels(&self) -> HashMap<String, LazyLabel> {
+        let mut lazy_labels = HashMap::new();
+        let mut to_collect = vec![self];
+        while let Some(labels) = to_collect.pop() {
+            let values: Vec<&TranslateObjectValue> = match labels {
+                LABELS::Object(hash_map) => hash_map.values().collect(),
+                LABELS::Computed(leaves) => leaves.iter().collect(),
+            };
+            for value in values {
+                match value {
+                    TranslateObjectValue::String(_) => (),
+                    TranslateObjectValue::Lazy(lazy_label) => {
+                        lazy_labels.insert(lazy_label.key.to_owned(), lazy_label.clone());
+                    }
+                    TranslateObjectValue::NestedLabels(nested_labels) => {
+                        to_collect.push(nested_labels)
+                    }
+                }
+            }
+        }
+        lazy_labels
+    }
+
+    // follow the member expression, then collect all the nested keys
+    #[cfg(test)]
     pub fn get_translation_keys_for_member_expr(
         &self,
         member_expr: &MemberExpr,
     ) -> anyhow::Result<HashSet<String>> {
-        let mut obj: &Box<Expr> = &member_expr.obj;
-        let mut prop_chain: Vec<&MemberProp> = vec![&member_expr.prop];
+        let mut obj: &Expr = &member_expr.obj;
+        let mut path: LabelPath = vec![PathSegment::from_member_prop(&member_expr.prop)?];
         loop {
-            match &**obj {
+            match obj {
                 Expr::Member(member_expr) => {
-                    prop_chain.push(&member_expr.prop);
+                    path.push(PathSegment::from_member_prop(&member_expr.prop)?);
                     obj = &member_expr.obj;
                 }
                 Expr::Ident(_) => break,
                 _ => bail!("member.obj can only be member_expr and ident"),
             }
         }
+        path.reverse();
 
-        let mut labels = self;
-        for prop in prop_chain.iter().rev() {
-            match prop {
-                MemberProp::Ident(ident_name) => {
-                    let sym = ident_name.sym.as_str();
-                    match labels {
-                        LABELS::Object(hash_map) => {
-                            let next = hash_map
-                                .get(sym)
-                                .context(format!("failed to access {}", sym))?;
-                            match next {
-                                TranslateObjectValue::String(s) => {
+        self.get_translation_keys_for_path(&path)
+    }
+
+    // Follow the path, then collect all the nested keys.
+    //
+    // Once a computed segment is found, e.g. `LABELS.attendants[type].name`, every
+    // child could be the one accessed at runtime, so keep following the rest of
+    // the path on all of them and collect what's reachable.
+    pub fn get_translation_keys_for_path(
+        &self,
+        path: &[PathSegment],
+    ) -> anyhow::Result<HashSet<String>> {
+        let mut nodes = vec![self];
+        let mut keys = HashSet::new();
+        // the labels where the first computed segment is applied to
+        let mut fanned_out_from: Option<&LABELS> = None;
+        for (i, segment) in path.iter().enumerate() {
+            let is_last_segment = i == path.len() - 1;
+            let mut next_nodes = vec![];
+            for labels in nodes {
+                match (segment, labels) {
+                    (PathSegment::Prop(sym), LABELS::Object(hash_map)) => {
+                        let next = match hash_map.get(sym) {
+                            Some(next) => next,
+                            // the other children might have it
+                            None if fanned_out_from.is_some() => continue,
+                            None => bail!("failed to access {}", sym),
+                        };
+                        match next {
+                            TranslateObjectValue::NestedLabels(nested_labels) => {
+                                next_nodes.push(nested_labels)
+                            }
+                            leaf => {
+                                let key = leaf.get_translation_key().unwrap().to_owned();
+                                if fanned_out_from.is_none() {
                                     // the simplest case, return directly
-                                    return Ok(HashSet::from([s.to_owned()]));
+                                    return Ok(HashSet::from([key]));
+                                }
+                                if is_last_segment {
+                                    keys.insert(key);
                                 }
+                            }
+                        }
+                    }
+                    (PathSegment::Prop(_), LABELS::Computed(_)) => {
+                        if fanned_out_from.is_none() {
+                            bail!("try to access computed with ident");
+                        }
+                        // computed keys are only known at runtime, any of them could match
+                        keys.extend(labels.get_all_translation_keys());
+                    }
+                    (PathSegment::Computed, LABELS::Object(hash_map)) => {
+                        fanned_out_from.get_or_insert(labels);
+                        for next in hash_map.values() {
+                            match next {
                                 TranslateObjectValue::NestedLabels(nested_labels) => {
-                                    labels = nested_labels
+                                    next_nodes.push(nested_labels)
+                                }
+                                leaf => {
+                                    if is_last_segment {
+                                        keys.insert(leaf.get_translation_key().unwrap().to_owned());
+                                    }
                                 }
                             }
                         }
-                        LABELS::Computed(_) => bail!("try to access computed with ident"),
+                    }
+                    (PathSegment::Computed, LABELS::Computed(_)) => {
+                        fanned_out_from.get_or_insert(labels);
+                        keys.extend(labels.get_all_translation_keys());
                     }
                 }
-                MemberProp::Computed(_) => {
-                    // once a computed prop found, stop and collect all the translation keys
-                    break;
+            }
+            nodes = next_nodes;
+        }
+
+        for labels in nodes {
+            keys.extend(labels.get_all_translation_keys());
+        }
+
+        // nothing matches the rest of the path, fallback to everything could be accessed
+        if keys.is_empty() {
+            if let Some(labels) = fanned_out_from {
+                keys.extend(labels.get_all_translation_keys());
+            }
+        }
+
+        Ok(keys)
+    }
+
+    // The leaves the path ends at, unlike `get_translation_keys_for_path` none
+    // are found when the path goes past them, like `LABELS.bird.toUpperCase`
+    // which is a method of the string.
+    pub fn get_leaves_for_path(&self, path: &[PathSegment]) -> Vec<&TranslateObjectValue> {
+        let mut nodes = vec![self];
+        let mut leaves = vec![];
+        for (i, segment) in path.iter().enumerate() {
+            let is_last_segment = i == path.len() - 1;
+            let mut next_nodes = vec![];
+            for labels in nodes {
+                let values: Vec<&TranslateObjectValue> = match (segment, labels) {
+                    (PathSegment::Prop(sym), LABELS::Object(hash_map)) => {
+                        hash_map.get(sym).into_iter().collect()
+                    }
+                    (PathSegment::Computed, LABELS::Object(hash_map)) => {
+                        hash_map.values().collect()
+                    }
+                    // computed keys are only known at runtime, any of them could match
+                    (_, LABELS::Computed(computed_leaves)) => computed_leaves.iter().collect(),
+                };
+                for value in values {
+                    match value {
+                        TranslateObjectValue::NestedLabels(nested_labels) => {
+                            next_nodes.push(nested_labels)
+                        }
+                        leaf => {
+                            if is_last_segment {
+                                leaves.push(leaf);
+                            }
+                        }
+                    }
                 }
-                MemberProp::PrivateName(_) => unimplemented!("what is private name? 🧐"),
             }
+            nodes = next_nodes;
         }
+        leaves
+    }
 
-        let mut to_collect = vec![labels];
+    pub fn get_all_translation_keys(&self) -> HashSet<String> {
+        let mut to_collect = vec![self];
         let mut keys = HashSet::new();
-        while to_collect.len() > 0 {
+        while !to_collect.is_empty() {
             let mut to_collect_next: Vec<&LABELS> = vec![];
             for labels in to_collect {
                 match labels {
@@ -109,185 +302,454 @@ impl LABELS {
                                 TranslateObjectValue::String(s) => {
                                     keys.insert(s.to_owned());
                                 }
+                                TranslateObjectValue::Lazy(lazy_label) => {
+                                    keys.insert(lazy_label.key.to_owned());
+                                }
                                 TranslateObjectValue::NestedLabels(nested_labels) => {
                                     to_collect_next.push(nested_labels)
                                 }
                             }
                         }
                     }
-                    LABELS::Computed(hash_set) => {
-                        keys.extend(hash_set.clone());
+                    LABELS::Computed(leaves) => {
+                        keys.extend(
+                            leaves
+                                .iter()
+                                .filter_map(|leaf| leaf.get_translation_key())
+                                .map(|key| key.to_owned()),
+                        );
                     }
                 }
             }
             to_collect = to_collect_next;
         }
-
-        Ok(keys)
+        keys
     }
 }
 
-fn flatten_translation_keys(object_lit: &ObjectLit) -> anyhow::Result<HashSet<String>> {
-    let mut translation_keys = HashSet::new();
-    for prop_or_spread in object_lit.props.iter() {
-        match prop_or_spread {
-            PropOrSpread::Prop(prop) => match &**prop {
-                Prop::KeyValue(key_value_prop) => match &*key_value_prop.value {
-                    Expr::Object(object_lit) => {
-                        translation_keys.extend(flatten_translation_keys(object_lit)?);
-                    }
-                    Expr::Lit(lit) => match lit {
-                        Lit::Str(Str { value, .. }) => {
-                            translation_keys.insert(value.to_string());
-                        }
-                        _ => bail!("value can only be string and object literal"),
-                    },
-                    Expr::Array(array_lit) => {
-                        let lazy_key = get_lazy_key_from_array_literal(array_lit)?;
-                        translation_keys.insert(lazy_key);
-                    }
-                    _ => bail!("value can only be string and object literal"),
-                },
-                _ => bail!("only key-value prop is allowed"),
-            },
-            PropOrSpread::Spread(_) => bail!("spread is not allowed"),
-        }
-    }
-    Ok(translation_keys)
+// One step of accessing the `LABELS`, `LABELS.a[type]` is `[Prop("a"), Computed]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Prop(String),
+    Computed,
 }
 
-fn insert_key_value_into_labels(
-    labels: &mut HashMap<String, TranslateObjectValue>,
-    key: String,
-    key_value_prop: &KeyValueProp,
-) -> anyhow::Result<()> {
-    labels.insert(
-        key,
-        match &*key_value_prop.value {
-            Expr::Object(object_lit) => {
-                TranslateObjectValue::NestedLabels(collect_labels_from_object_literal(object_lit)?)
+pub type LabelPath = Vec<PathSegment>;
+
+impl PathSegment {
+    pub fn from_member_prop(member_prop: &MemberProp) -> anyhow::Result<Self> {
+        match member_prop {
+            MemberProp::Ident(ident_name) => Ok(PathSegment::Prop(ident_name.sym.to_string())),
+            MemberProp::Computed(ComputedPropName { expr, .. }) => {
+                Ok(PathSegment::from_computed_expr(expr))
             }
-            Expr::Lit(lit) => match lit {
-                Lit::Str(Str { value, .. }) => TranslateObjectValue::String(value.to_string()),
-                _ => bail!("value can only be string and object literal"),
-            },
-            Expr::Array(array_lit) => {
-                let lazy_key = get_lazy_key_from_array_literal(array_lit)?;
-                TranslateObjectValue::String(lazy_key)
+            MemberProp::PrivateName(_) => bail!("labels can't have private names"),
+        }
+    }
+
+    // `LABELS["bird"]` is as precise as `LABELS.bird`
+    pub fn from_computed_expr(expr: &Expr) -> Self {
+        match expr {
+            Expr::Lit(Lit::Str(Str { value, .. })) => PathSegment::Prop(value.to_string()),
+            Expr::Lit(Lit::Num(Number { value, .. })) => PathSegment::Prop(value.to_string()),
+            _ => PathSegment::Computed,
+        }
+    }
+
+    pub fn from_prop_name(prop_name: &PropName) -> Self {
+        match prop_name {
+            PropName::Ident(ident_name) => PathSegment::Prop(ident_name.sym.to_string()),
+            PropName::Str(s) => PathSegment::Prop(s.value.to_string()),
+            PropName::Num(n) => PathSegment::Prop(n.value.to_string()),
+            PropName::Computed(ComputedPropName { expr, .. }) => {
+                PathSegment::from_computed_expr(expr)
             }
-            _ => bail!("value can only be string and object literal"),
-        },
-    );
-    Ok(())
+            PropName::BigInt(b) => PathSegment::Prop(b.value.to_string()),
+        }
+    }
 }
 
-pub fn collect_labels_from_object_literal(object_lit: &ObjectLit) -> anyhow::Result<LABELS> {
-    let mut labels = HashMap::new();
-    let mut translation_keys = HashSet::new();
-    let mut has_computed_key = false;
-    for prop_or_spread in object_lit.props.iter() {
-        match prop_or_spread {
-            PropOrSpread::Prop(prop) => match &**prop {
-                Prop::KeyValue(key_value_prop) => match &key_value_prop.key {
-                    PropName::Str(s) => {
-                        if has_computed_key {
-                            bail!(
-                                "mixing string with computed keys is not allowed: {}",
-                                s.value
-                            );
-                        }
-                        insert_key_value_into_labels(
-                            &mut labels,
-                            s.value.to_string(),
-                            key_value_prop,
-                        )?;
-                    }
-                    PropName::Num(n) => {
-                        if has_computed_key {
-                            bail!(
-                                "mixing number with computed keys is not allowed: {}",
-                                n.value
-                            );
+// A named property of the object passed to `translate`, like `pets.cat` in
+// `{ pets: { cat: "i18n.cat" } }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelProperty {
+    pub path: Vec<String>,
+    // `name: value`
+    pub span: Span,
+}
+
+impl LabelProperty {
+    // `LABELS.pets`, `LABELS.pets.cat` and `LABELS.pets[type]` all reach
+    // `pets.cat`, `LABELS.pets.dog` doesn't
+    pub fn is_reached_by(&self, path: &[PathSegment]) -> bool {
+        self.path
+            .iter()
+            .zip(path)
+            .all(|(name, segment)| match segment {
+                PathSegment::Prop(prop) => prop == name,
+                PathSegment::Computed => true,
+            })
+    }
+}
+
+// the span of `'<i18n key>'` in `['<i18n key>', 'lazy']`
+fn get_lazy_key_span(array_lit: &ArrayLit) -> Span {
+    match array_lit.elems.first() {
+        Some(Some(ExprOrSpread { expr, .. })) => expr.span(),
+        _ => array_lit.span,
+    }
+}
+
+// Collects the `LABELS` tree from the object literal passed to `translate`.
+#[derive(Default)]
+pub struct LabelsCollector {
+    // module scoped string constants, used to fold keys like `${PREFIX}.title`
+    constants: StringConstants,
+
+    // translation key -> whether the first label using it is lazy
+    keys: HashMap<String, bool>,
+
+    // the duplicated properties and keys found so far
+    pub diagnostics: Vec<Diagnostic>,
+
+    // the named properties collected so far, the computed ones aren't named
+    pub properties: Vec<LabelProperty>,
+
+    // every translation key collected so far and where it's written, for the
+    // lints checking the keys
+    pub key_spans: Vec<(String, Span)>,
+
+    // the path of the property being collected
+    path: Vec<String>,
+}
+
+impl LabelsCollector {
+    pub fn new(constants: StringConstants) -> Self {
+        Self {
+            constants,
+            ..Default::default()
+        }
+    }
+
+    pub fn collect(&mut self, object_lit: &ObjectLit) -> anyhow::Result<LABELS> {
+        let mut labels = HashMap::new();
+        let mut leaves = vec![];
+        let mut has_computed_key = false;
+        for prop_or_spread in object_lit.props.iter() {
+            match prop_or_spread {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value_prop) => match &key_value_prop.key {
+                        PropName::Str(s) => {
+                            if has_computed_key {
+                                bail!(
+                                    "mixing string with computed keys is not allowed: {}",
+                                    s.value
+                                );
+                            }
+                            self.insert_key_value_into_labels(
+                                &mut labels,
+                                s.value.to_string(),
+                                key_value_prop,
+                            )?;
                         }
-                        insert_key_value_into_labels(
-                            &mut labels,
-                            n.value.to_string(),
-                            key_value_prop,
-                        )?;
-                    }
-                    PropName::Ident(id) => {
-                        if has_computed_key {
-                            bail!("mixing ident with computed keys is not allowed: {}", id.sym);
+                        PropName::Num(n) => {
+                            if has_computed_key {
+                                bail!(
+                                    "mixing number with computed keys is not allowed: {}",
+                                    n.value
+                                );
+                            }
+                            self.insert_key_value_into_labels(
+                                &mut labels,
+                                n.value.to_string(),
+                                key_value_prop,
+                            )?;
                         }
-                        insert_key_value_into_labels(
-                            &mut labels,
-                            id.sym.to_string(),
-                            key_value_prop,
-                        )?;
-                    }
-                    PropName::Computed(_) => {
-                        if labels.len() != 0 {
-                            bail!("mixing string and computed keys is not allowed");
+                        PropName::Ident(id) => {
+                            if has_computed_key {
+                                bail!("mixing ident with computed keys is not allowed: {}", id.sym);
+                            }
+                            self.insert_key_value_into_labels(
+                                &mut labels,
+                                id.sym.to_string(),
+                                key_value_prop,
+                            )?;
                         }
-                        has_computed_key = true;
-                        match &*key_value_prop.value {
-                            Expr::Object(object_lit) => {
-                                translation_keys.extend(flatten_translation_keys(object_lit)?);
+                        PropName::Computed(_) => {
+                            if !labels.is_empty() {
+                                bail!("mixing string and computed keys is not allowed");
                             }
-                            Expr::Lit(lit) => match lit {
-                                Lit::Str(Str { value, .. }) => {
-                                    translation_keys.insert(value.to_string());
+                            has_computed_key = true;
+                            match &*key_value_prop.value {
+                                Expr::Object(object_lit) => {
+                                    leaves.extend(self.flatten_leaves(object_lit)?);
+                                }
+                                Expr::Array(array_lit) => {
+                                    let lazy_label =
+                                        self.get_lazy_label_from_array_literal(array_lit)?;
+                                    self.record_key(
+                                        &lazy_label.key,
+                                        true,
+                                        get_lazy_key_span(array_lit),
+                                    );
+                                    leaves.push(TranslateObjectValue::Lazy(lazy_label));
+                                }
+                                expr => {
+                                    let key = self.get_translation_key(expr)?;
+                                    self.record_key(&key, false, expr.span());
+                                    leaves.push(TranslateObjectValue::String(key));
                                 }
-                                _ => bail!("value can only be string and object literal"),
-                            },
-                            Expr::Array(array_lit) => {
-                                let lazy_key = get_lazy_key_from_array_literal(array_lit)?;
-                                translation_keys.insert(lazy_key);
                             }
-                            _ => bail!("value can only be string and object literal"),
                         }
-                    }
-                    _ => bail!("key can only be string or computed"),
+                        _ => bail!("key can only be string or computed"),
+                    },
+                    _ => bail!("only key-value prop is allowed"),
                 },
-                _ => bail!("only key-value prop is allowed"),
-            },
-            PropOrSpread::Spread(_) => bail!("spread is not allowed"),
+                PropOrSpread::Spread(_) => bail!("spread is not allowed"),
+            }
         }
+
+        Ok(match has_computed_key {
+            true => LABELS::Computed(leaves),
+            false => LABELS::Object(labels),
+        })
     }
 
-    Ok(match has_computed_key {
-        true => LABELS::Computed(translation_keys),
-        false => LABELS::Object(labels),
-    })
-}
+    fn flatten_leaves(
+        &mut self,
+        object_lit: &ObjectLit,
+    ) -> anyhow::Result<Vec<TranslateObjectValue>> {
+        let mut leaves = vec![];
+        for prop_or_spread in object_lit.props.iter() {
+            match prop_or_spread {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value_prop) => match &*key_value_prop.value {
+                        Expr::Object(object_lit) => {
+                            leaves.extend(self.flatten_leaves(object_lit)?);
+                        }
+                        Expr::Array(array_lit) => {
+                            let lazy_label = self.get_lazy_label_from_array_literal(array_lit)?;
+                            self.record_key(&lazy_label.key, true, get_lazy_key_span(array_lit));
+                            leaves.push(TranslateObjectValue::Lazy(lazy_label));
+                        }
+                        expr => {
+                            let key = self.get_translation_key(expr)?;
+                            self.record_key(&key, false, expr.span());
+                            leaves.push(TranslateObjectValue::String(key));
+                        }
+                    },
+                    _ => bail!("only key-value prop is allowed"),
+                },
+                PropOrSpread::Spread(_) => bail!("spread is not allowed"),
+            }
+        }
+        Ok(leaves)
+    }
 
-fn get_lazy_key_from_array_literal(array_lit: &ArrayLit) -> anyhow::Result<String> {
-    if array_lit.elems.len() != 2 {
-        bail!("array lit can only be ['<i18n key>', 'lazy']");
+    fn insert_key_value_into_labels(
+        &mut self,
+        labels: &mut HashMap<String, TranslateObjectValue>,
+        key: String,
+        key_value_prop: &KeyValueProp,
+    ) -> anyhow::Result<()> {
+        // like JavaScript, the last one wins
+        if labels.contains_key(&key) {
+            self.diagnostics.push(Diagnostic::new(
+                Rule::DuplicateLabelProperty,
+                format!(
+                    "property {} is defined more than once, the last one wins",
+                    key
+                ),
+                key_value_prop.key.span(),
+            ));
+        }
+        self.path.push(key.clone());
+        let value = match &*key_value_prop.value {
+            Expr::Object(object_lit) => {
+                TranslateObjectValue::NestedLabels(self.collect(object_lit)?)
+            }
+            Expr::Array(array_lit) => {
+                let lazy_label = self.get_lazy_label_from_array_literal(array_lit)?;
+                self.record_key(&lazy_label.key, true, get_lazy_key_span(array_lit));
+                TranslateObjectValue::Lazy(lazy_label)
+            }
+            expr => {
+                let translation_key = self.get_translation_key(expr)?;
+                self.record_key(&translation_key, false, expr.span());
+                TranslateObjectValue::String(translation_key)
+            }
+        };
+        self.properties.push(LabelProperty {
+            path: self.path.clone(),
+            span: key_value_prop.span(),
+        });
+        self.path.pop();
+        labels.insert(key, value);
+        Ok(())
     }
-    match &*(array_lit.elems[1].as_ref().unwrap().expr) {
-        Expr::Lit(lit) => match lit {
-            Lit::Str(Str { value, .. }) => {
-                if value.to_string() != "lazy" {
-                    bail!("array lit can only be ['<i18n key>', 'lazy']");
-                }
+
+    // A translation key used by two labels of the same `translate` object is a
+    // duplicate, or a conflict if one label is lazy and the other isn't.
+    fn record_key(&mut self, translation_key: &str, lazy: bool, span: Span) {
+        self.key_spans.push((translation_key.to_string(), span));
+        match self.keys.get(translation_key) {
+            Some(&seen_lazy) if seen_lazy == lazy => self.diagnostics.push(Diagnostic::new(
+                Rule::DuplicateLabelKey,
+                format!("\"{}\" is already used by another label", translation_key),
+                span,
+            )),
+            Some(_) => self.diagnostics.push(Diagnostic::new(
+                Rule::ConflictingLabelKey,
+                format!(
+                    "\"{}\" is used by both a lazy and a plain label",
+                    translation_key
+                ),
+                span,
+            )),
+            None => {
+                self.keys.insert(translation_key.to_string(), lazy);
             }
-            _ => bail!("array lit can only be ['<i18n key>', 'lazy']"),
-        },
-        _ => bail!("array lit can only be ['<i18n key>', 'lazy']"),
+        }
     }
-    match &*(array_lit.elems[0].as_ref().unwrap().expr) {
-        Expr::Lit(lit) => match lit {
-            Lit::Str(Str { value, .. }) => return Ok(value.to_string()),
-            _ => bail!("array lit can only be ['<i18n key>', 'lazy']"),
-        },
-        _ => bail!("array lit can only be ['<i18n key>', 'lazy']"),
+
+    // String literals, template literals and string concatenations are all
+    // allowed, the parts can't be evaluated at build time become wildcards,
+    // e.g. `i18n.${type}.title` -> `i18n.*.title`.
+    fn get_translation_key(&self, expr: &Expr) -> anyhow::Result<String> {
+        match fold_string_expr(expr, &self.constants) {
+            Some(key) => Ok(key),
+            None if has_literal_wildcard(expr) => {
+                bail!(
+                    "`{}` is reserved for the unknown parts of keys",
+                    KEY_PATTERN_WILDCARD
+                )
+            }
+            None => bail!("value can only be string and object literal"),
+        }
+    }
+
+    // `['<i18n key>', 'lazy']`, optionally followed by an object of modifiers
+    // `{ plural: true, defaultText: "...", description: "..." }`. Unknown
+    // modifiers are ignored.
+    fn get_lazy_label_from_array_literal(
+        &mut self,
+        array_lit: &ArrayLit,
+    ) -> anyhow::Result<LazyLabel> {
+        if array_lit.elems.len() != 2 && array_lit.elems.len() != 3 {
+            bail!(LAZY_FORMAT_ERROR);
+        }
+        let mut elems = vec![];
+        for elem in array_lit.elems.iter() {
+            match elem {
+                Some(ExprOrSpread { spread: None, expr }) => elems.push(&**expr),
+                // holes like `[, 'lazy']` and spreads
+                _ => bail!(LAZY_FORMAT_ERROR),
+            }
+        }
+        match elems[1] {
+            Expr::Lit(Lit::Str(Str { value, .. })) if *value == "lazy" => (),
+            _ => bail!(LAZY_FORMAT_ERROR),
+        }
+        let mut lazy_label = match fold_string_expr(elems[0], &self.constants) {
+            Some(key) => LazyLabel::new(key),
+            None => bail!(LAZY_FORMAT_ERROR),
+        };
+        if let Some(modifiers) = elems.get(2) {
+            match modifiers {
+                Expr::Object(object_lit) => self.apply_lazy_modifiers(&mut lazy_label, object_lit),
+                modifiers => self.report_invalid_modifier(
+                    "the third element of a lazy label can only be an object of modifiers"
+                        .to_string(),
+                    modifiers.span(),
+                ),
+            }
+        }
+        Ok(lazy_label)
+    }
+
+    // The modifiers which can't be understood are ignored, the label is still
+    // a lazy label.
+    fn report_invalid_modifier(&mut self, message: String, span: Span) {
+        self.diagnostics.push(Diagnostic::new(
+            Rule::InvalidLazyModifier,
+            format!("{}, it's ignored", message),
+            span,
+        ));
+    }
+
+    fn apply_lazy_modifiers(&mut self, lazy_label: &mut LazyLabel, object_lit: &ObjectLit) {
+        for prop_or_spread in object_lit.props.iter() {
+            let key_value_prop = match prop_or_spread {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value_prop) => key_value_prop,
+                    prop => {
+                        self.report_invalid_modifier(
+                            "lazy modifiers can only be `name: value`".to_string(),
+                            prop.span(),
+                        );
+                        continue;
+                    }
+                },
+                PropOrSpread::Spread(spread) => {
+                    self.report_invalid_modifier(
+                        "lazy modifiers can only be `name: value`".to_string(),
+                        spread.span(),
+                    );
+                    continue;
+                }
+            };
+            let name = match &key_value_prop.key {
+                PropName::Ident(ident_name) => ident_name.sym.to_string(),
+                PropName::Str(s) => s.value.to_string(),
+                key => {
+                    self.report_invalid_modifier(
+                        "lazy modifiers can only be named".to_string(),
+                        key.span(),
+                    );
+                    continue;
+                }
+            };
+            let value = &*key_value_prop.value;
+            match name.as_str() {
+                "plural" => match value {
+                    Expr::Lit(Lit::Bool(Bool { value, .. })) => lazy_label.plural = *value,
+                    _ => self.report_invalid_modifier(
+                        "lazy modifier plural can only be a boolean".to_string(),
+                        value.span(),
+                    ),
+                },
+                "defaultText" => match fold_string_expr(value, &self.constants) {
+                    Some(default_text) => lazy_label.default_text = Some(default_text),
+                    None => self.report_invalid_modifier(
+                        "lazy modifier defaultText can only be a string".to_string(),
+                        value.span(),
+                    ),
+                },
+                "description" => match fold_string_expr(value, &self.constants) {
+                    Some(description) => lazy_label.description = Some(description),
+                    None => self.report_invalid_modifier(
+                        "lazy modifier description can only be a string".to_string(),
+                        value.span(),
+                    ),
+                },
+                _ => self.report_invalid_modifier(
+                    format!("unknown lazy modifier {}", name),
+                    key_value_prop.key.span(),
+                ),
+            }
+        }
     }
 }
 
+#[cfg(test)]
+pub fn collect_labels_from_object_literal(object_lit: &ObjectLit) -> anyhow::Result<LABELS> {
+    LabelsCollector::default().collect(object_lit)
+}
+
 #[cfg(test)]
 mod extract_labels_tests {
     use super::*;
+    use crate::key_pattern::collect_string_constants;
     use crate::test_utils;
     use anyhow::Context;
     use swc_core::ecma::visit::{Visit, VisitWith};
@@ -311,7 +773,7 @@ mod extract_labels_tests {
         let module = test_utils::parse_module(&input)?;
         let mut visitor = Visitor::new();
         module.visit_with(&mut visitor);
-        Ok(visitor.object_lit.context("failed to get object literal")?)
+        visitor.object_lit.context("failed to get object literal")
     }
 
     #[test]
@@ -492,7 +954,7 @@ mod extract_labels_tests {
         let labels = collect_labels_from_object_literal(&object_lit).unwrap();
         let object = labels.get_object().unwrap();
         assert_eq!(
-            object.get("bird").unwrap().get_string().unwrap(),
+            object.get("bird").unwrap().get_lazy().unwrap().key,
             "i18n.bird"
         );
         let size_computed = object
@@ -535,7 +997,6 @@ mod extract_labels_tests {
     }
 
     #[test]
-    #[should_panic(expected = "array lit can only be ['<i18n key>', 'lazy']")]
     fn lazy_wrong_format_3() {
         let object_lit = parse_object_lit(
             r#"
@@ -545,9 +1006,278 @@ mod extract_labels_tests {
             "#,
         )
         .unwrap();
+        let mut labels_collector = LabelsCollector::default();
+        let labels = labels_collector.collect(&object_lit).unwrap();
+        assert_eq!(
+            labels
+                .get_object()
+                .unwrap()
+                .get("bird")
+                .unwrap()
+                .get_lazy()
+                .unwrap(),
+            &LazyLabel::new("i18n.bird".to_string())
+        );
+        assert_eq!(
+            labels_collector
+                .diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            [(
+                Rule::InvalidLazyModifier,
+                "the third element of a lazy label can only be an object of modifiers, it's ignored"
+            )]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "array lit can only be ['<i18n key>', 'lazy']")]
+    fn lazy_hole() {
+        let object_lit = parse_object_lit(
+            r#"
+            {
+                bird: [, "lazy"],
+            }
+            "#,
+        )
+        .unwrap();
         collect_labels_from_object_literal(&object_lit).unwrap();
     }
 
+    #[test]
+    fn lazy_modifiers() {
+        let object_lit = parse_object_lit(
+            r#"
+            {
+                bird: ["i18n.bird", "lazy", {}],
+                cat: ["i18n.cat", "lazy", { plural: true, unknown: 42 }],
+                dog: ["i18n.dog", "lazy", {
+                    defaultText: "Hello {name}",
+                    description: "greeting " + "for dogs",
+                }],
+            }
+            "#,
+        )
+        .unwrap();
+        let mut labels_collector = LabelsCollector::default();
+        let labels = labels_collector.collect(&object_lit).unwrap();
+        let object = labels.get_object().unwrap();
+        assert_eq!(
+            object.get("bird").unwrap().get_lazy().unwrap(),
+            &LazyLabel::new("i18n.bird".to_string())
+        );
+        assert_eq!(
+            labels_collector
+                .diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message.as_str())
+                .collect::<Vec<_>>(),
+            ["unknown lazy modifier unknown, it's ignored"]
+        );
+        let cat = object.get("cat").unwrap().get_lazy().unwrap();
+        assert!(cat.plural);
+        assert_eq!(cat.default_text, None);
+        let dog = object.get("dog").unwrap().get_lazy().unwrap();
+        assert!(!dog.plural);
+        assert_eq!(dog.default_text.as_deref(), Some("Hello {name}"));
+        assert_eq!(dog.description.as_deref(), Some("greeting for dogs"));
+    }
+
+    #[test]
+    fn lazy_wrong_modifier() {
+        let object_lit = parse_object_lit(
+            r#"
+            {
+                bird: ["i18n.bird", "lazy", { plural: "yes", defaultText: 42 }],
+            }
+            "#,
+        )
+        .unwrap();
+        let mut labels_collector = LabelsCollector::default();
+        let labels = labels_collector.collect(&object_lit).unwrap();
+        assert_eq!(
+            labels
+                .get_object()
+                .unwrap()
+                .get("bird")
+                .unwrap()
+                .get_lazy()
+                .unwrap(),
+            &LazyLabel::new("i18n.bird".to_string())
+        );
+        assert_eq!(
+            labels_collector
+                .diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    Rule::InvalidLazyModifier,
+                    "lazy modifier plural can only be a boolean, it's ignored"
+                ),
+                (
+                    Rule::InvalidLazyModifier,
+                    "lazy modifier defaultText can only be a string, it's ignored"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn template_literal_and_concatenation() {
+        let object_lit = parse_object_lit(
+            r#"
+            {
+                bird: `i18n.${"bird"}`,
+                cat: "i18n." + "cat",
+                title: `i18n.${type}.title`,
+                desc: ["i18n." + type + ".desc", "lazy"],
+                size: {
+                    [SIZE.small]: `i18n.${type}.small`,
+                    [SIZE.large]: `i18n.${type}.large`,
+                },
+            }
+            "#,
+        )
+        .unwrap();
+        let labels = collect_labels_from_object_literal(&object_lit).unwrap();
+        let object = labels.get_object().unwrap();
+        assert_eq!(
+            object.get("bird").unwrap().get_string().unwrap(),
+            "i18n.bird"
+        );
+        assert_eq!(object.get("cat").unwrap().get_string().unwrap(), "i18n.cat");
+        assert_eq!(
+            object.get("title").unwrap().get_string().unwrap(),
+            "i18n.*.title"
+        );
+        assert_eq!(
+            object.get("desc").unwrap().get_lazy().unwrap().key,
+            "i18n.*.desc"
+        );
+        let size_computed = object
+            .get("size")
+            .unwrap()
+            .get_labels()
+            .unwrap()
+            .get_computed()
+            .unwrap();
+        assert!(size_computed.contains("i18n.*.small"));
+        assert!(size_computed.contains("i18n.*.large"));
+    }
+
+    #[test]
+    fn string_constants() {
+        let module = test_utils::parse_module(
+            r#"
+            const PREFIX = "i18n.pet";
+            const LABELS = translate({
+                bird: `${PREFIX}.bird`,
+                cat: PREFIX + ".cat",
+            })
+            "#,
+        )
+        .unwrap();
+        let mut visitor = Visitor::new();
+        module.visit_with(&mut visitor);
+        let labels = LabelsCollector::new(collect_string_constants(&module))
+            .collect(&visitor.object_lit.unwrap())
+            .unwrap();
+        let object = labels.get_object().unwrap();
+        assert_eq!(
+            object.get("bird").unwrap().get_string().unwrap(),
+            "i18n.pet.bird"
+        );
+        assert_eq!(
+            object.get("cat").unwrap().get_string().unwrap(),
+            "i18n.pet.cat"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`*` is reserved for the unknown parts of keys")]
+    fn literal_wildcard() {
+        let object_lit = parse_object_lit(
+            r#"
+            {
+                bird: "i18n.*.bird",
+            }
+            "#,
+        )
+        .unwrap();
+        collect_labels_from_object_literal(&object_lit).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "value can only be string and object literal")]
+    fn not_a_string() {
+        let object_lit = parse_object_lit(
+            r#"
+            {
+                bird: getKey("bird"),
+            }
+            "#,
+        )
+        .unwrap();
+        collect_labels_from_object_literal(&object_lit).unwrap();
+    }
+
+    #[test]
+    fn duplicated_properties_and_keys() {
+        let object_lit = parse_object_lit(
+            r#"{
+                title: "i18n.title",
+                name: "i18n.title",
+                desc: ["i18n.desc", "lazy"],
+                pets: {
+                    cat: "i18n.cat",
+                    "cat": "i18n.desc",
+                },
+                title: "i18n.name",
+            }"#,
+        )
+        .unwrap();
+        let mut labels_collector = LabelsCollector::default();
+        let labels = labels_collector.collect(&object_lit).unwrap();
+        assert_eq!(
+            labels
+                .get_object()
+                .unwrap()
+                .get("title")
+                .unwrap()
+                .get_string()
+                .unwrap(),
+            "i18n.name"
+        );
+        assert_eq!(
+            labels_collector
+                .diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    Rule::DuplicateLabelKey,
+                    r#""i18n.title" is already used by another label"#
+                ),
+                (
+                    Rule::DuplicateLabelProperty,
+                    "property cat is defined more than once, the last one wins"
+                ),
+                (
+                    Rule::ConflictingLabelKey,
+                    r#""i18n.desc" is used by both a lazy and a plain label"#
+                ),
+                (
+                    Rule::DuplicateLabelProperty,
+                    "property title is defined more than once, the last one wins"
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn complex() {
         let object_lit = parse_object_lit(
@@ -590,7 +1320,7 @@ mod extract_labels_tests {
             "i18n.pet.party"
         );
         assert_eq!(
-            object.get("desc").unwrap().get_string().unwrap(),
+            object.get("desc").unwrap().get_lazy().unwrap().key,
             "i18n.pet.party.desc"
         );
 
@@ -606,7 +1336,7 @@ mod extract_labels_tests {
             "i18n.bird"
         );
         assert_eq!(
-            bird_object.get("desc").unwrap().get_string().unwrap(),
+            bird_object.get("desc").unwrap().get_lazy().unwrap().key,
             "i18n.bird.desc"
         );
         let bird_size_computed = bird_object
@@ -631,7 +1361,7 @@ mod extract_labels_tests {
             "i18n.cat"
         );
         assert_eq!(
-            cat_object.get("desc").unwrap().get_string().unwrap(),
+            cat_object.get("desc").unwrap().get_lazy().unwrap().key,
             "i18n.cat.desc"
         );
         let cat_size_computed = cat_object
@@ -656,7 +1386,7 @@ mod extract_labels_tests {
             "i18n.dog"
         );
         assert_eq!(
-            dog_object.get("desc").unwrap().get_string().unwrap(),
+            dog_object.get("desc").unwrap().get_lazy().unwrap().key,
             "i18n.dog.desc"
         );
         let dog_size_computed = dog_object
@@ -691,13 +1421,14 @@ mod access_labels_tests {
             self.object_lit = Some(node.clone());
         }
     }
+    #[allow(clippy::needless_borrow)]
     fn parse_object_lit(input: &str) -> anyhow::Result<ObjectLit> {
         let module = test_utils::parse_module(&input)?;
         let mut object_lit_visitor = ObjectLitVisitor::new();
         module.visit_with(&mut object_lit_visitor);
-        Ok(object_lit_visitor
+        object_lit_visitor
             .object_lit
-            .context("failed to get object literal")?)
+            .context("failed to get object literal")
     }
 
     struct MemberExprVisitor {
@@ -718,9 +1449,9 @@ mod access_labels_tests {
         let module = test_utils::parse_module(&input)?;
         let mut member_expr_visitor = MemberExprVisitor::new();
         module.visit_with(&mut member_expr_visitor);
-        Ok(member_expr_visitor
+        member_expr_visitor
             .member_expr
-            .context("failed to get member expression")?)
+            .context("failed to get member expression")
     }
 
     macro_rules! assert_keys {
@@ -884,6 +1615,17 @@ mod access_labels_tests {
             ],
 
             "LABELS[type].size.small" => [
+                "i18n.bird.small",
+                "i18n.bird.large",
+                "i18n.cat.small",
+                "i18n.cat.large",
+                "i18n.dog.small",
+                "i18n.dog.large",
+            ],
+
+            "LABELS[type].name" => ["i18n.bird", "i18n.cat", "i18n.dog"],
+            "LABELS[type].desc" => ["i18n.bird.desc", "i18n.cat.desc", "i18n.dog.desc"],
+            "LABELS[type].unknown" => [
                 "i18n.pet.party",
                 "i18n.pet.party.desc",
                 "i18n.bird",
@@ -898,7 +1640,7 @@ mod access_labels_tests {
                 "i18n.dog.desc",
                 "i18n.dog.small",
                 "i18n.dog.large",
-            ]
+            ],
         );
     }
 }
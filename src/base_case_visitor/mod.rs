@@ -1,4 +1,13 @@
+mod aliases;
 mod labels;
+mod params;
+mod symbols;
 mod visitor;
 
-pub use visitor::get_labels_usage;
+pub use labels::{LabelCall, LazyLabel};
+pub use params::{ModuleContext, ModuleSummary, ProjectSummaries, SymbolRef, MAX_SUMMARY_ROUNDS};
+pub use visitor::{get_labels_usage, get_labels_usage_following_props, summarize_params, Options};
+// shared with the other extractors
+pub(crate) use labels::{LabelsCollector, PathSegment, TranslateObjectValue, LABELS};
+pub(crate) use symbols::{SymbolScope, SymbolVisitor};
+pub(crate) use visitor::get_prop_name;
@@ -0,0 +1,145 @@
+use super::labels::{LabelPath, PathSegment, LABELS};
+use std::collections::{HashMap, HashSet};
+
+// A top level symbol of a module, e.g. the component receiving a part of the
+// LABELS through its props.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolRef {
+    pub module: String,
+    pub symbol: String,
+}
+
+// `reader` reads `path` of the `param`-th argument of a function, like
+// `const Bar = (props) => props.labels.b.bird` reads `[labels, b, bird]` of
+// the 0th argument. The reader isn't necessarily the function itself when it
+// passes the argument further to other components.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamRead {
+    pub reader: SymbolRef,
+    pub param: usize,
+    pub path: LabelPath,
+}
+
+pub type ParamReads = HashSet<ParamRead>;
+
+impl ParamRead {
+    // The rest of the path once the `param`-th argument is `arg`.
+    pub fn rest_for_arg(&self, arg: usize) -> Option<&[PathSegment]> {
+        match self.param == arg {
+            true => Some(&self.path),
+            false => None,
+        }
+    }
+
+    // JSX elements pass a single props object, `<Bar labels={LABELS.a} />`
+    // passes `LABELS.a` as `labels` of the 0th argument.
+    pub fn rest_for_prop(&self, prop: &str) -> Option<&[PathSegment]> {
+        if self.param != 0 {
+            return None;
+        }
+        match self.path.split_first() {
+            Some((PathSegment::Prop(name), rest)) => match name == prop {
+                true => Some(rest),
+                false => None,
+            },
+            Some((PathSegment::Computed, rest)) => Some(rest),
+            // the whole props object is read
+            None => Some(&[]),
+        }
+    }
+}
+
+// What the top level functions of a module do with their arguments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleSummary {
+    // local symbol -> params read by the function
+    functions: HashMap<String, ParamReads>,
+    // exported name -> local symbol, a default export is `default`
+    exports: HashMap<String, String>,
+    // the LABELS defined in the module, the modules importing them use them
+    // as their own
+    labels: Option<LABELS>,
+}
+
+impl ModuleSummary {
+    pub fn new(
+        functions: HashMap<String, ParamReads>,
+        exports: HashMap<String, String>,
+        labels: Option<LABELS>,
+    ) -> Self {
+        Self {
+            functions,
+            exports,
+            labels,
+        }
+    }
+
+    pub fn get_export(&self, exported: &str) -> Option<&ParamReads> {
+        self.functions.get(self.exports.get(exported)?)
+    }
+
+    // `export const LABELS = translate({ /* ... */ })` or `module.exports = { LABELS }`
+    pub fn get_exported_labels(&self, exported: &str) -> Option<&LABELS> {
+        match self.exports.get(exported)? == "LABELS" {
+            true => self.labels.as_ref(),
+            false => None,
+        }
+    }
+}
+
+// module path -> the summary of the module
+pub type ProjectSummaries = HashMap<String, ModuleSummary>;
+
+// A function passing its arguments to another one only reads what the other
+// one reads, within a module or across the imports, so the summaries are
+// computed again until nothing changes. The rounds are bounded since recursive
+// components could grow the paths forever, the results are then partial: the
+// keys only found past the last round are missing.
+pub const MAX_SUMMARY_ROUNDS: usize = 8;
+
+// Follow the parts of the LABELS passed as JSX props or call arguments into
+// the receiving functions of the same module or of the imported modules.
+pub struct ModuleContext<'a> {
+    // the path of the module being visited, as the keys of `summaries`
+    pub module_path: &'a str,
+    // the summaries of the other modules, from a previous round
+    pub summaries: &'a ProjectSummaries,
+    // import source -> module path
+    pub resolve_import: &'a dyn Fn(&str) -> Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(param: usize, props: &[&str]) -> ParamRead {
+        ParamRead {
+            reader: SymbolRef {
+                module: "test.js".to_string(),
+                symbol: "Bar".to_string(),
+            },
+            param,
+            path: props
+                .iter()
+                .map(|prop| PathSegment::Prop(prop.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn rest_for_prop() {
+        let prop = |name: &str| PathSegment::Prop(name.to_string());
+        assert_eq!(
+            read(0, &["labels", "b"]).rest_for_prop("labels").unwrap(),
+            &[prop("b")]
+        );
+        assert!(read(0, &["labels", "b"]).rest_for_prop("other").is_none());
+        assert!(read(1, &["labels", "b"]).rest_for_prop("labels").is_none());
+        assert!(read(0, &[]).rest_for_prop("labels").unwrap().is_empty());
+        assert_eq!(
+            read(1, &["labels", "b"]).rest_for_arg(1).unwrap(),
+            &[prop("labels"), prop("b")]
+        );
+        assert!(read(1, &["labels"]).rest_for_arg(0).is_none());
+    }
+}
@@ -0,0 +1,694 @@
+use super::visitor::{
+    get_commonjs_export, get_commonjs_exports, get_commonjs_member_name, get_exported_names,
+    get_object_values, get_pat_binding_idents, get_prop_name, get_value_kind, is_commonjs_export,
+    Options,
+};
+use crate::symbol::{
+    get_display_name_for_module, Symbol, SymbolKind, ANONYMOUS_DEFAULT_EXPORT_NAME,
+    MODULE_SIDE_EFFECTS_NAME,
+};
+use std::collections::HashMap;
+use swc_core::{
+    common::{Span, Spanned},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+// The top level symbol being visited, see `Symbol`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum SymbolId {
+    Ident(Id),
+    // a symbol without a binding of its own, like `Admin.Foo` declared in a
+    // namespace or `module.exports.render` of `exports.render = () => { /* … */ }`
+    Named(String),
+    // export default () => { /* … */ }
+    AnonymousDefault,
+    // registerToast(LABELS.saved)
+    ModuleSideEffects,
+}
+
+impl SymbolId {
+    pub fn get_name(&self) -> String {
+        match self {
+            SymbolId::Ident(id) => id.0.to_string(),
+            SymbolId::Named(name) => name.clone(),
+            SymbolId::AnonymousDefault => ANONYMOUS_DEFAULT_EXPORT_NAME.to_string(),
+            SymbolId::ModuleSideEffects => MODULE_SIDE_EFFECTS_NAME.to_string(),
+        }
+    }
+}
+
+// The top level symbol being visited and the symbols found so far, what an
+// extractor attributes its keys to.
+pub(crate) struct SymbolScope {
+    // the display name of the anonymous default export, see `Symbol`
+    default_display_name: String,
+
+    options: Options,
+
+    pub current_id: Option<SymbolId>,
+
+    // the member of the current symbol being visited, like `#render` of
+    // `Foo#render`, see `Options::qualified_symbols`
+    pub current_member: String,
+
+    pub symbols: HashMap<String, Symbol>,
+}
+
+impl SymbolScope {
+    pub fn new(module_path: &str, options: Options) -> Self {
+        Self {
+            default_display_name: get_display_name_for_module(module_path),
+            options,
+            current_id: None,
+            current_member: String::new(),
+            symbols: HashMap::new(),
+        }
+    }
+
+    // `Foo`, or `Foo#render` in its `render` method with `Options::qualified_symbols`
+    pub fn get_current_symbol(&self) -> Option<String> {
+        let current_id = self.current_id.as_ref()?;
+        Some(format!("{}{}", current_id.get_name(), self.current_member))
+    }
+
+    // the symbols with the names they're exported as
+    pub fn into_symbols(self, module: &Module) -> HashMap<String, Symbol> {
+        let mut symbols = self.symbols;
+        for (local, exported_names) in get_exported_names(module) {
+            if let Some(symbol) = symbols.get_mut(&local) {
+                symbol.exported_as = exported_names;
+            }
+        }
+        symbols
+    }
+}
+
+// Walks the top level of a module, visiting every declaration, export and
+// statement as the symbol it belongs to, so all the extractors name the same
+// code the same way. The visitor finds the keys, the walker tells it whose
+// they are through the `SymbolScope`.
+pub(crate) trait SymbolVisitor: Visit + Sized {
+    fn symbol_scope(&mut self) -> &mut SymbolScope;
+
+    // Visits the bindings of `pat` if they're more than symbols, like the
+    // aliases of `const { bird } = LABELS`, returns whether they were visited.
+    fn visit_alias_symbols(&mut self, _pat: &Pat, _init: &Expr) -> bool {
+        false
+    }
+
+    // The symbol is used from now on, until it's left.
+    fn enter_symbol(&mut self, id: SymbolId, kind: SymbolKind, span: Span) {
+        let scope = self.symbol_scope();
+        let name = id.get_name();
+        let default_display_name = &scope.default_display_name;
+        scope.symbols.entry(name.clone()).or_insert_with(|| {
+            let mut symbol = Symbol::new(name, kind, span);
+            if kind == SymbolKind::Default {
+                symbol.display_name = default_display_name.clone();
+            }
+            symbol
+        });
+        scope.current_id = Some(id);
+    }
+
+    fn enter_ident_symbol(&mut self, ident: &Ident, kind: SymbolKind) {
+        self.enter_symbol(SymbolId::Ident(ident.to_id()), kind, ident.span);
+    }
+
+    fn leave_symbol(&mut self) {
+        self.symbol_scope().current_id = None;
+    }
+
+    fn visit_member(
+        &mut self,
+        member: String,
+        kind: SymbolKind,
+        span: Span,
+        visit: impl FnOnce(&mut Self),
+    ) {
+        let scope = self.symbol_scope();
+        let len = scope.current_member.len();
+        scope.current_member.push_str(&member);
+        if let Some(name) = scope.get_current_symbol() {
+            scope
+                .symbols
+                .entry(name.clone())
+                .or_insert_with(|| Symbol::new(name, kind, span));
+        }
+        visit(self);
+        self.symbol_scope().current_member.truncate(len);
+    }
+
+    // `class Foo { static render() {} handleClick = () => {} }` uses the LABELS
+    // as `Foo.render` and `Foo#handleClick`
+    fn visit_class_member_symbols(&mut self, class: &Class) {
+        if !self.symbol_scope().options.qualified_symbols {
+            class.visit_with(self);
+            return;
+        }
+        class.decorators.visit_with(self);
+        class.super_class.visit_with(self);
+        let separator = |is_static: bool| match is_static {
+            true => ".",
+            false => "#",
+        };
+        for class_member in &class.body {
+            match class_member {
+                ClassMember::Constructor(constructor) => self.visit_member(
+                    "#constructor".to_string(),
+                    SymbolKind::Function,
+                    constructor.span,
+                    |v| constructor.visit_with(v),
+                ),
+                ClassMember::Method(ClassMethod {
+                    key,
+                    function,
+                    is_static,
+                    ..
+                }) => match get_prop_name(key) {
+                    Some(name) => self.visit_member(
+                        format!("{}{}", separator(*is_static), name),
+                        SymbolKind::Function,
+                        class_member.span(),
+                        |v| function.visit_with(v),
+                    ),
+                    None => class_member.visit_with(self),
+                },
+                ClassMember::PrivateMethod(PrivateMethod {
+                    key,
+                    function,
+                    is_static,
+                    ..
+                }) => self.visit_member(
+                    format!("{}#{}", separator(*is_static), key.name),
+                    SymbolKind::Function,
+                    class_member.span(),
+                    |v| function.visit_with(v),
+                ),
+                ClassMember::ClassProp(ClassProp {
+                    key,
+                    value,
+                    is_static,
+                    ..
+                }) => match get_prop_name(key) {
+                    Some(name) => self.visit_member(
+                        format!("{}{}", separator(*is_static), name),
+                        get_value_kind(value.as_deref()),
+                        class_member.span(),
+                        |v| value.visit_with(v),
+                    ),
+                    None => class_member.visit_with(self),
+                },
+                ClassMember::PrivateProp(PrivateProp {
+                    key,
+                    value,
+                    is_static,
+                    ..
+                }) => self.visit_member(
+                    format!("{}#{}", separator(*is_static), key.name),
+                    get_value_kind(value.as_deref()),
+                    class_member.span(),
+                    |v| value.visit_with(v),
+                ),
+                _ => class_member.visit_with(self),
+            }
+        }
+    }
+
+    // `const handlers = { onError() {} }` uses the LABELS as `handlers.onError`
+    fn visit_object_member_symbols(&mut self, object_lit: &ObjectLit) {
+        if !self.symbol_scope().options.qualified_symbols {
+            object_lit.visit_with(self);
+            return;
+        }
+        for prop_or_spread in &object_lit.props {
+            let prop = match prop_or_spread {
+                PropOrSpread::Prop(prop) => prop,
+                PropOrSpread::Spread(_) => {
+                    prop_or_spread.visit_with(self);
+                    continue;
+                }
+            };
+            let key = match &**prop {
+                Prop::KeyValue(KeyValueProp { key, .. })
+                | Prop::Method(MethodProp { key, .. })
+                | Prop::Getter(GetterProp { key, .. })
+                | Prop::Setter(SetterProp { key, .. }) => key,
+                _ => {
+                    prop.visit_with(self);
+                    continue;
+                }
+            };
+            let name = match get_prop_name(key) {
+                Some(name) => name,
+                None => {
+                    prop.visit_with(self);
+                    continue;
+                }
+            };
+            let kind = match &**prop {
+                Prop::KeyValue(KeyValueProp { value, .. }) => get_value_kind(Some(value)),
+                _ => SymbolKind::Function,
+            };
+            self.visit_member(format!(".{}", name), kind, prop.span(), |v| match &**prop {
+                Prop::KeyValue(KeyValueProp { value, .. }) => v.visit_symbol_value(value),
+                Prop::Method(MethodProp { function, .. }) => function.visit_with(v),
+                Prop::Getter(GetterProp { body, .. }) => body.visit_with(v),
+                Prop::Setter(SetterProp { param, body, .. }) => {
+                    param.visit_with(v);
+                    body.visit_with(v);
+                }
+                _ => (),
+            });
+        }
+    }
+
+    // Every binding of the pattern is a symbol, the parts of an object or array
+    // literal initializer are attributed to the bindings they're destructured
+    // into. When the initializer can't be split, like a function call, all the
+    // bindings use what it uses.
+    fn visit_pattern_symbols(&mut self, pat: &Pat, init: Option<&Expr>) {
+        let init = match init {
+            Some(init) => init,
+            None => return,
+        };
+        if self.visit_alias_symbols(pat, init) {
+            return;
+        }
+        match (pat, init) {
+            (Pat::Ident(BindingIdent { id, .. }), _) => {
+                self.enter_ident_symbol(id, get_value_kind(Some(init)));
+                self.visit_symbol_value(init);
+                self.leave_symbol();
+            }
+            (_, Expr::Paren(ParenExpr { expr, .. })) => self.visit_pattern_symbols(pat, Some(expr)),
+            // the default value is used when the init is undefined
+            (Pat::Assign(AssignPat { left, right, .. }), _) => {
+                self.visit_pattern_symbols(left, Some(init));
+                self.visit_pattern_symbols(left, Some(right));
+            }
+            (Pat::Array(ArrayPat { elems, .. }), Expr::Array(ArrayLit { elems: values, .. }))
+                if values.iter().all(|value| {
+                    !matches!(
+                        value,
+                        Some(ExprOrSpread {
+                            spread: Some(_),
+                            ..
+                        })
+                    )
+                }) =>
+            {
+                let value_at = |i: usize| match values.get(i) {
+                    Some(Some(ExprOrSpread { expr, .. })) => Some(&**expr),
+                    _ => None,
+                };
+                for (i, elem) in elems.iter().enumerate() {
+                    match elem {
+                        // const [A, ...rest] = [a, b, c]
+                        Some(Pat::Rest(RestPat { arg, .. })) => {
+                            for value in (i..values.len()).filter_map(value_at) {
+                                self.visit_pattern_symbols(arg, Some(value));
+                            }
+                            return;
+                        }
+                        Some(elem) => self.visit_pattern_symbols(elem, value_at(i)),
+                        // const [, B] = [a, b]
+                        None => {
+                            if let Some(value) = value_at(i) {
+                                self.visit_bindings_of(pat, value);
+                            }
+                        }
+                    }
+                }
+                for value in (elems.len()..values.len()).filter_map(value_at) {
+                    self.visit_bindings_of(pat, value);
+                }
+            }
+            (Pat::Object(ObjectPat { props, .. }), Expr::Object(object_lit)) => {
+                let mut values = match get_object_values(object_lit) {
+                    Some(values) => values,
+                    None => return self.visit_bindings_of(pat, init),
+                };
+                for prop in props {
+                    match prop {
+                        ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                            match get_prop_name(key) {
+                                Some(name) => {
+                                    if let Some(init) = values.remove(&name) {
+                                        self.visit_pattern_symbols(value, Some(&init));
+                                    }
+                                }
+                                // const { [key]: A } = { a, b }
+                                None => {
+                                    for init in std::mem::take(&mut values).values() {
+                                        self.visit_pattern_symbols(value, Some(init));
+                                    }
+                                }
+                            }
+                        }
+                        ObjectPatProp::Assign(AssignPatProp { key, value, .. }) => {
+                            let init = values.remove(&*key.sym);
+                            self.enter_ident_symbol(key, get_value_kind(init.as_ref()));
+                            if let Some(init) = init {
+                                self.visit_symbol_value(&init);
+                            }
+                            value.visit_with(self);
+                            self.leave_symbol();
+                        }
+                        // const { A, ...rest } = { A: a, B: b }
+                        ObjectPatProp::Rest(RestPat { arg, .. }) => {
+                            for init in std::mem::take(&mut values).values() {
+                                self.visit_pattern_symbols(arg, Some(init));
+                            }
+                        }
+                    }
+                }
+                // evaluated but not bound to anything
+                for init in values.values() {
+                    self.visit_bindings_of(pat, init);
+                }
+            }
+            _ => self.visit_bindings_of(pat, init),
+        }
+    }
+
+    // all the bindings of the pattern use what the node uses
+    fn visit_bindings_of(&mut self, pat: &Pat, node: &Expr) {
+        for ident in get_pat_binding_idents(pat) {
+            self.enter_ident_symbol(ident, SymbolKind::Variable);
+            self.visit_symbol_value(node);
+            self.leave_symbol();
+        }
+    }
+
+    fn visit_symbol_init(&mut self, init: &Option<Box<Expr>>) {
+        if let Some(init) = init {
+            self.visit_symbol_value(init);
+        }
+    }
+
+    // The value of a top level symbol or of one of its members.
+    fn visit_symbol_value(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Object(object_lit) => self.visit_object_member_symbols(object_lit),
+            Expr::Class(ClassExpr { class, .. }) => self.visit_class_member_symbols(class),
+            Expr::Paren(ParenExpr { expr, .. })
+            | Expr::TsAs(TsAsExpr { expr, .. })
+            | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+            | Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => {
+                self.visit_symbol_value(expr)
+            }
+            _ => expr.visit_with(self),
+        }
+    }
+
+    // `namespace Admin { export const Foo = () => {} }` uses the LABELS as
+    // `Admin.Foo`, `prefix` is the enclosing namespace like `Admin.` if any.
+    // `declare` blocks only describe types, they have nothing to visit.
+    fn visit_namespace(&mut self, ts_module: &TsModuleDecl, prefix: &str) {
+        if ts_module.declare {
+            return;
+        }
+        let mut namespace = match &ts_module.id {
+            TsModuleName::Ident(ident) => format!("{}{}", prefix, ident.sym),
+            TsModuleName::Str(_) => return,
+        };
+        let mut body = ts_module.body.as_ref();
+        while let Some(namespace_body) = body {
+            match namespace_body {
+                // namespace Admin.Users { /* … */ }
+                TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                    id, body: inner, ..
+                }) => {
+                    namespace = format!("{}.{}", namespace, id.sym);
+                    body = Some(&**inner);
+                }
+                TsNamespaceBody::TsModuleBlock(TsModuleBlock { body, .. }) => {
+                    for module_item in body {
+                        match module_item {
+                            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                                decl,
+                                ..
+                            }))
+                            | ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                                self.visit_namespace_decl(decl, &namespace)
+                            }
+                            // the body of a namespace runs once the module is imported
+                            ModuleItem::Stmt(stmt) => {
+                                self.enter_symbol(
+                                    SymbolId::ModuleSideEffects,
+                                    SymbolKind::ModuleSideEffect,
+                                    stmt.span(),
+                                );
+                                stmt.visit_with(self);
+                                self.leave_symbol();
+                            }
+                            _ => (),
+                        }
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    fn visit_namespace_decl(&mut self, decl: &Decl, namespace: &str) {
+        let qualify = |ident: &Ident| SymbolId::Named(format!("{}.{}", namespace, ident.sym));
+        match decl {
+            Decl::Class(ClassDecl { ident, class, .. }) => {
+                self.enter_symbol(qualify(ident), SymbolKind::Class, ident.span);
+                self.visit_class_member_symbols(class);
+                self.leave_symbol();
+            }
+            Decl::Fn(FnDecl {
+                ident, function, ..
+            }) => {
+                self.enter_symbol(qualify(ident), SymbolKind::Function, ident.span);
+                function.visit_with(self);
+                self.leave_symbol();
+            }
+            // every binding of a pattern uses what the initializer uses
+            Decl::Var(var_decl) => {
+                for var_declarator in &var_decl.decls {
+                    let kind = match &var_declarator.name {
+                        Pat::Ident(_) => get_value_kind(var_declarator.init.as_deref()),
+                        _ => SymbolKind::Variable,
+                    };
+                    for ident in get_pat_binding_idents(&var_declarator.name) {
+                        self.enter_symbol(qualify(ident), kind, ident.span);
+                        self.visit_symbol_init(&var_declarator.init);
+                        self.leave_symbol();
+                    }
+                }
+            }
+            Decl::TsModule(ts_module) => {
+                self.visit_namespace(ts_module, &format!("{}.", namespace))
+            }
+            Decl::TsEnum(ts_enum) => self.visit_enum(ts_enum, qualify(&ts_enum.id)),
+            _ => (),
+        }
+    }
+
+    // `enum Message { Saved = LABELS.saved }` uses the LABELS as `Message`, or
+    // as `Message.Saved` with `Options::qualified_symbols`.
+    fn visit_enum(&mut self, ts_enum: &TsEnumDecl, id: SymbolId) {
+        self.enter_symbol(id, SymbolKind::Enum, ts_enum.id.span);
+        for member in &ts_enum.members {
+            let init = match &member.init {
+                Some(init) => init,
+                None => continue,
+            };
+            match self.symbol_scope().options.qualified_symbols {
+                true => {
+                    let name = match &member.id {
+                        TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+                        TsEnumMemberId::Str(str) => str.value.to_string(),
+                    };
+                    self.visit_member(
+                        format!(".{}", name),
+                        SymbolKind::Variable,
+                        member.span,
+                        |v| init.visit_with(v),
+                    );
+                }
+                false => init.visit_with(self),
+            }
+        }
+        self.leave_symbol();
+    }
+
+    // `class Foo {}`, `function foo() {}`, `const foo = init, bar = init`,
+    // `namespace Admin { /* … */ }` and `enum Message { /* … */ }`, exported or not
+    fn visit_decl_symbols(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Class(ClassDecl { ident, class, .. }) => {
+                self.enter_ident_symbol(ident, SymbolKind::Class);
+                self.visit_class_member_symbols(class);
+                self.leave_symbol();
+            }
+            Decl::Fn(FnDecl {
+                ident, function, ..
+            }) => {
+                self.enter_ident_symbol(ident, SymbolKind::Function);
+                function.visit_with(self);
+                self.leave_symbol();
+            }
+            Decl::Var(var_decl) => {
+                for var_decl in &var_decl.decls {
+                    match (&var_decl.name, var_decl.init.as_deref()) {
+                        // const ATTENDANTS = LABELS.attendants
+                        (pat, Some(init)) if self.visit_alias_symbols(pat, init) => (),
+                        (Pat::Ident(BindingIdent { id, .. }), init) => {
+                            self.enter_ident_symbol(id, get_value_kind(init));
+                            self.visit_symbol_init(&var_decl.init);
+                            self.leave_symbol();
+                        }
+                        // const { Header, Footer } = createComponents(LABELS)
+                        // const [A, B] = [() => { /* … */ }, () => { /* … */ }]
+                        (pat, init) => self.visit_pattern_symbols(pat, init),
+                    }
+                }
+            }
+            Decl::TsModule(ts_module) => self.visit_namespace(ts_module, ""),
+            Decl::TsEnum(ts_enum) => self.visit_enum(ts_enum, SymbolId::Ident(ts_enum.id.to_id())),
+            _ => (),
+        }
+    }
+
+    fn visit_top_level_symbols(&mut self, module: &Module) {
+        for module_item in &module.body {
+            match module_item {
+                ModuleItem::ModuleDecl(module_decl) => match module_decl {
+                    ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+                        self.visit_decl_symbols(decl)
+                    }
+                    ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => match decl {
+                        DefaultDecl::Class(ClassExpr { ident, class }) => {
+                            match ident {
+                                // export default class ClassName { /* … */ }
+                                Some(ident) => self.enter_ident_symbol(ident, SymbolKind::Class),
+                                // export default class { /* … */ }
+                                None => self.enter_symbol(
+                                    SymbolId::AnonymousDefault,
+                                    SymbolKind::Default,
+                                    class.span,
+                                ),
+                            }
+                            self.visit_class_member_symbols(class);
+                            self.leave_symbol();
+                        }
+                        DefaultDecl::Fn(FnExpr { ident, function }) => {
+                            match ident {
+                                // export default function functionName() { /* … */ }
+                                Some(ident) => self.enter_ident_symbol(ident, SymbolKind::Function),
+                                // export default function () { /* … */ }
+                                None => self.enter_symbol(
+                                    SymbolId::AnonymousDefault,
+                                    SymbolKind::Default,
+                                    function.span,
+                                ),
+                            }
+                            function.visit_with(self);
+                            self.leave_symbol();
+                        }
+                        DefaultDecl::TsInterfaceDecl(_) => (),
+                    },
+                    // `export = Foo` is imported as the default export
+                    ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. })
+                    | ModuleDecl::TsExportAssignment(TsExportAssignment { expr, .. }) => {
+                        match &**expr {
+                            // export default name1;
+                            // name1 is visited where it's declared, see `get_exported_names`
+                            Expr::Ident(_) => (),
+                            // export default [name1, name2];
+                            Expr::Array(array_lit) => {
+                                self.enter_symbol(
+                                    SymbolId::AnonymousDefault,
+                                    SymbolKind::Default,
+                                    array_lit.span,
+                                );
+                                array_lit.visit_with(self);
+                                self.leave_symbol();
+                            }
+                            // export default { name1, name2 };
+                            Expr::Object(object_lit) => {
+                                self.enter_symbol(
+                                    SymbolId::AnonymousDefault,
+                                    SymbolKind::Default,
+                                    object_lit.span,
+                                );
+                                self.visit_object_member_symbols(object_lit);
+                                self.leave_symbol();
+                            }
+                            // export default () => { /* … */ };
+                            // export default connect(mapStateToProps)(Foo);
+                            _ => {
+                                self.enter_symbol(
+                                    SymbolId::AnonymousDefault,
+                                    SymbolKind::Default,
+                                    expr.span(),
+                                );
+                                expr.visit_with(self);
+                                self.leave_symbol();
+                            }
+                        }
+                    }
+                    _ => (),
+                },
+                ModuleItem::Stmt(stmt) => match stmt {
+                    Stmt::Decl(decl) => self.visit_decl_symbols(decl),
+                    // module.exports = { render }, exports.render = () => { /* … */ }
+                    Stmt::Expr(ExprStmt { expr, .. }) if is_commonjs_export(expr) => {
+                        for (exported, value) in get_commonjs_exports(expr) {
+                            let id = match exported {
+                                // module.exports = () => { /* … */ }
+                                None => SymbolId::AnonymousDefault,
+                                Some(exported) => {
+                                    SymbolId::Named(get_commonjs_member_name(&exported))
+                                }
+                            };
+                            let kind = match id {
+                                SymbolId::AnonymousDefault => SymbolKind::Default,
+                                _ => get_value_kind(Some(value)),
+                            };
+                            self.enter_symbol(id, kind, value.span());
+                            self.visit_symbol_value(value);
+                            self.leave_symbol();
+                        }
+                        // module.exports = { render() { /* … */ } }
+                        if let Some((None, Expr::Object(object_lit))) = get_commonjs_export(expr) {
+                            for prop in &object_lit.props {
+                                if let PropOrSpread::Prop(prop) = prop {
+                                    if let Prop::Method(MethodProp { key, function }) = &**prop {
+                                        if let Some(name) = get_prop_name(key) {
+                                            self.enter_symbol(
+                                                SymbolId::Named(get_commonjs_member_name(&name)),
+                                                SymbolKind::Function,
+                                                function.span,
+                                            );
+                                            function.visit_with(self);
+                                            self.leave_symbol();
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    // registerToast(LABELS.saved), if (cond) { /* … */ }, (() => { /* … */ })()
+                    _ => {
+                        self.enter_symbol(
+                            SymbolId::ModuleSideEffects,
+                            SymbolKind::ModuleSideEffect,
+                            stmt.span(),
+                        );
+                        stmt.visit_with(self);
+                        self.leave_symbol();
+                    }
+                },
+            }
+        }
+    }
+}
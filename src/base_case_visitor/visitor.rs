@@ -1,61 +1,86 @@
-use super::labels::{collect_labels_from_object_literal, LABELS};
-use crate::anonymous_default_export::get_anonymous_default_export_id;
+use super::aliases::{extend_label_paths, Aliases, LabelPaths, LabelRoot, RootedPath};
+use super::labels::{
+    LabelCall, LabelPath, LabelProperty, LabelsCollector, LazyLabel, PathSegment, LABELS,
+};
+use super::params::{
+    ModuleContext, ModuleSummary, ParamRead, ParamReads, SymbolRef, MAX_SUMMARY_ROUNDS,
+};
+use super::symbols::{SymbolId, SymbolScope, SymbolVisitor};
+use crate::diagnostic::{Diagnostic, Rule};
+use crate::key_pattern::collect_string_constants;
+use crate::symbol::{Symbol, SymbolKind, ANONYMOUS_DEFAULT_EXPORT_NAME};
+use anyhow::{bail, Context};
 use std::collections::{HashMap, HashSet};
-use swc_core::ecma::{
-    ast::*,
-    visit::{Visit, VisitWith},
+use swc_core::{
+    common::{Span, Spanned},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
 };
 
 struct LabelVisitor {
     labels: Option<LABELS>,
+
+    // the duplicated properties and keys of the LABELS
+    diagnostics: Vec<Diagnostic>,
+
+    // the named properties of the LABELS, to find the dead ones
+    properties: Vec<LabelProperty>,
 }
 
 impl LabelVisitor {
     pub fn new() -> Self {
-        Self { labels: None }
+        Self {
+            labels: None,
+            diagnostics: vec![],
+            properties: vec![],
+        }
     }
-}
 
-// Only find the module scopped `LABELS = translate({ /* ... */})`.
-// `LABELS` could be defined in smaller scopped, let's ignore it now,
-// since our latest style guide only allow defining `LABELS` at module
-// scope.
-impl Visit for LabelVisitor {
-    fn visit_module(&mut self, node: &Module) {
+    // Only find the module scopped `LABELS = translate({ /* ... */})`.
+    // `LABELS` could be defined in smaller scopped, let's ignore it now,
+    // since our latest style guide only allow defining `LABELS` at module
+    // scope.
+    #[allow(clippy::single_match)]
+    fn visit_module(&mut self, node: &Module) -> anyhow::Result<()> {
+        let mut labels_collector = LabelsCollector::new(collect_string_constants(node));
         for module_item in &node.body {
             match module_item {
-                ModuleItem::Stmt(stmt) => {
-                    match stmt {
-                        Stmt::Decl(decl) => match decl {
-                            Decl::Var(var_decl) => {
-                                for var_declarator in var_decl.decls.iter() {
-                                    match labels_translate_args(var_declarator) {
-                                        Some(args) => {
-                                            if args.len() == 0 {
-                                                panic!("translate should have at least 1 argument");
-                                            }
-                                            let first_arg = &args[0];
-                                            match &*first_arg.expr {
-                                                Expr::Object(object_lit) => {
-                                                    self.labels = Some(
-                                                    collect_labels_from_object_literal(object_lit)
-                                                        .expect("collect labels from the object literal"));
-                                                }
-                                                _ => (),
-                                            }
-                                        }
-                                        None => (),
+                // export const LABELS = translate({ /* ... */ })
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var_decl),
+                    ..
+                }))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                    for var_declarator in var_decl.decls.iter() {
+                        match labels_translate_args(var_declarator) {
+                            Some(args) => {
+                                if args.is_empty() {
+                                    bail!("translate should have at least 1 argument");
+                                }
+                                let first_arg = &args[0];
+                                match &*first_arg.expr {
+                                    Expr::Object(object_lit) => {
+                                        self.labels = Some(
+                                            labels_collector
+                                                .collect(object_lit)
+                                                .context("failed to collect the LABELS")?,
+                                        );
+                                        self.diagnostics.append(&mut labels_collector.diagnostics);
+                                        self.properties.append(&mut labels_collector.properties);
                                     }
+                                    _ => (),
                                 }
                             }
-                            _ => (),
-                        },
-                        _ => (),
+                            None => (),
+                        }
                     }
                 }
                 _ => (),
             }
         }
+        Ok(())
     }
 }
 
@@ -86,308 +111,1690 @@ fn labels_translate_args(decl: &VarDeclarator) -> Option<&Vec<ExprOrSpread>> {
     }
 }
 
-struct LabelUsageVisitor {
-    // current_id is used to track which identifier is using the LABELS
-    current_id: Option<Id>,
+struct LabelUsageVisitor<'a> {
+    // which symbol is using the LABELS
+    scope: SymbolScope,
+
+    module_path: &'a str,
+
+    // labels is extracted by the LabelVisitor, a module without LABELS is
+    // still visited to summarise what its functions do with their arguments
+    labels: Option<LABELS>,
+
+    // the keys of the lazy labels, which are expected to be called
+    lazy_keys: HashSet<String>,
+
+    // set when following the parts of the LABELS passed to other functions
+    context: Option<&'a ModuleContext<'a>>,
+
+    // top level function -> what it reads from its arguments
+    summaries: HashMap<SymbolId, ParamReads>,
 
-    // labels is extracted by the LabelVisitor
-    labels: LABELS,
+    // local binding -> (import source, imported name)
+    imports: HashMap<Id, (String, String)>,
+
+    aliases: Aliases,
+
+    // functions returning a part of the LABELS, like
+    // `const getPetLabels = (type) => LABELS.attendants[type]`
+    returns: HashMap<Id, LabelPaths>,
+
+    // what the functions being visited return, the innermost one is the last
+    return_frames: Vec<LabelPaths>,
 
     label_usage: HashMap<String, HashSet<String>>,
+
+    // symbol -> translation key -> where the symbol uses the key
+    occurrences: HashMap<String, HashMap<String, Vec<Span>>>,
+
+    // symbol -> translation key -> where the symbol calls the key
+    calls: HashMap<String, HashMap<String, Vec<LabelCall>>>,
+
+    // keys read by the symbols of other modules
+    external_usage: HashMap<SymbolRef, HashSet<String>>,
+
+    diagnostics: Vec<Diagnostic>,
+
+    // the LABELS itself is passed around or rendered, any of its labels could
+    // be read
+    whole_labels_used: bool,
+
+    // the paths of the LABELS read by the module, a property none of them
+    // reaches is dead even if another property shares its key
+    read_paths: HashSet<LabelPath>,
+
+    // the local names of the top level bindings exported by the module
+    exported: HashSet<String>,
 }
 
-impl LabelUsageVisitor {
-    pub fn new(labels: LABELS) -> Self {
+impl<'a> LabelUsageVisitor<'a> {
+    pub fn new(
+        labels: Option<LABELS>,
+        module_path: &'a str,
+        context: Option<&'a ModuleContext<'a>>,
+        options: Options,
+    ) -> Self {
         Self {
-            current_id: None,
+            scope: SymbolScope::new(module_path, options),
+            module_path,
+            lazy_keys: labels
+                .as_ref()
+                .map(|labels| labels.get_lazy_labels().into_keys().collect())
+                .unwrap_or_default(),
             labels,
+            context,
+            summaries: HashMap::new(),
+            imports: HashMap::new(),
+            aliases: Aliases::default(),
+            returns: HashMap::new(),
+            return_frames: vec![],
             label_usage: HashMap::new(),
+            occurrences: HashMap::new(),
+            calls: HashMap::new(),
+            external_usage: HashMap::new(),
+            diagnostics: vec![],
+            whole_labels_used: false,
+            read_paths: HashSet::new(),
+            exported: HashSet::new(),
         }
     }
-}
 
-impl Visit for LabelUsageVisitor {
-    fn visit_member_expr(&mut self, node: &MemberExpr) {
-        if self.current_id.is_some() && is_labels_obj(node) {
-            let translation_keys = self
-                .labels
-                .get_translation_keys_for_member_expr(node)
-                .unwrap();
-            if translation_keys.len() == 0 {
-                return;
+    // `LABELS.a.b`, `LABELS?.a`, `L.b` with `const L = LABELS.a`,
+    // `cond ? LABELS.a : LABELS.b`, `getPetLabels(type).name`, ...
+    fn get_label_paths(&self, expr: &Expr) -> Option<LabelPaths> {
+        match expr {
+            Expr::Ident(ident) => match ident.sym == "LABELS" {
+                true => Some(HashSet::from([RootedPath::labels()])),
+                false => self.aliases.get(&ident.to_id()).cloned(),
+            },
+            Expr::Member(member_expr) => self.get_label_paths_for_member_expr(member_expr),
+            Expr::OptChain(OptChainExpr { base, .. }) => match &**base {
+                OptChainBase::Member(member_expr) => {
+                    self.get_label_paths_for_member_expr(member_expr)
+                }
+                OptChainBase::Call(_) => None,
+            },
+            Expr::Paren(ParenExpr { expr, .. })
+            | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+            | Expr::TsAs(TsAsExpr { expr, .. })
+            | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. }) => self.get_label_paths(expr),
+            Expr::Cond(CondExpr { cons, alt, .. }) => {
+                self.union_label_paths(self.get_label_paths(cons), self.get_label_paths(alt))
             }
-            let current_symbol = self.current_id.as_ref().unwrap().0.to_string();
-            if !self.label_usage.contains_key(&current_symbol) {
-                self.label_usage
-                    .insert(current_symbol.clone(), HashSet::new());
+            Expr::Bin(BinExpr {
+                op: BinaryOp::LogicalOr | BinaryOp::LogicalAnd | BinaryOp::NullishCoalescing,
+                left,
+                right,
+                ..
+            }) => self.union_label_paths(self.get_label_paths(left), self.get_label_paths(right)),
+            Expr::Seq(SeqExpr { exprs, .. }) => self.get_label_paths(exprs.last()?),
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match &**callee {
+                Expr::Ident(ident) => self.returns.get(&ident.to_id()).cloned(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn get_label_paths_for_member_expr(&self, member_expr: &MemberExpr) -> Option<LabelPaths> {
+        let paths = self.get_label_paths(&member_expr.obj)?;
+        let segment = PathSegment::from_member_prop(&member_expr.prop).ok()?;
+        Some(extend_label_paths(&paths, segment))
+    }
+
+    fn union_label_paths(
+        &self,
+        a: Option<LabelPaths>,
+        b: Option<LabelPaths>,
+    ) -> Option<LabelPaths> {
+        match (a, b) {
+            (Some(mut a), Some(b)) => {
+                a.extend(b);
+                Some(a)
             }
-            self.label_usage
-                .entry(current_symbol)
-                .and_modify(|set| set.extend(translation_keys));
+            (a, b) => a.or(b),
         }
     }
 
-    fn visit_module(&mut self, node: &Module) {
-        for module_item in &node.body {
-            match module_item {
-                ModuleItem::ModuleDecl(module_decl) => match module_decl {
-                    ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => match decl {
-                        // export class Foo {}
-                        Decl::Class(ClassDecl { ident, class, .. }) => {
-                            self.current_id = Some(ident.to_id());
-                            class.visit_with(self);
-                            self.current_id = None;
+    // Bind every identifier of the pattern to the part of the LABELS it holds.
+    fn declare_aliases(&mut self, pat: &Pat, paths: LabelPaths) {
+        match pat {
+            // const L = LABELS.a
+            Pat::Ident(BindingIdent { id, .. }) => {
+                self.aliases.assign(id.to_id(), paths);
+            }
+            Pat::Object(ObjectPat { props, .. }) => {
+                for prop in props {
+                    match prop {
+                        // const { walk: { cat } } = LABELS
+                        ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                            let paths =
+                                extend_label_paths(&paths, PathSegment::from_prop_name(key));
+                            self.declare_aliases(value, paths);
                         }
-                        // export function foo() {}
-                        Decl::Fn(FnDecl {
-                            ident, function, ..
-                        }) => {
-                            self.current_id = Some(ident.to_id());
-                            function.visit_with(self);
-                            self.current_id = None;
+                        // const { bird } = LABELS
+                        ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+                            let paths =
+                                extend_label_paths(&paths, PathSegment::Prop(key.sym.to_string()));
+                            self.aliases.assign(key.to_id(), paths);
                         }
-                        // export const foo = init, bar = init
-                        Decl::Var(var_decl) => {
-                            for var_decl in &var_decl.decls {
-                                match &var_decl.name {
-                                    Pat::Ident(BindingIdent { id, .. }) => {
-                                        self.current_id = Some(id.to_id());
-                                        var_decl.init.visit_with(self);
-                                        self.current_id = None;
-                                    }
-                                    _ => (),
-                                }
-                            }
+                        // const { bird, ...rest } = LABELS
+                        // `rest` holds the remaining props, refer to the whole object
+                        ObjectPatProp::Rest(RestPat { arg, .. }) => {
+                            self.declare_aliases(arg, paths.clone());
                         }
-                        _ => (),
-                    },
-                    ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => match decl {
-                        DefaultDecl::Class(ClassExpr { ident, class }) => match ident {
-                            // export default class ClassName { /* … */ }
-                            Some(ident) => {
-                                self.current_id = Some(ident.to_id());
-                                class.visit_with(self);
-                                self.current_id = None;
-                            }
-                            // export default class { /* … */ }
-                            None => {
-                                self.current_id = Some(get_anonymous_default_export_id());
-                                class.visit_with(self);
-                                self.current_id = None;
-                            }
-                        },
-                        DefaultDecl::Fn(FnExpr { ident, function }) => match ident {
-                            // export default function functionName() { /* … */ }
-                            Some(ident) => {
-                                self.current_id = Some(ident.to_id());
-                                function.visit_with(self);
-                                self.current_id = None;
-                            }
-                            // export default function () { /* … */ }
-                            None => {
-                                self.current_id = Some(get_anonymous_default_export_id());
-                                function.visit_with(self);
-                                self.current_id = None;
-                            }
-                        },
-                        DefaultDecl::TsInterfaceDecl(_) => (),
-                    },
-                    ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => {
-                        match &**expr {
-                            // export default name1;
-                            Expr::Ident(_) => (),
-                            // export default [name1, name2];
-                            Expr::Array(array_lit) => {
-                                self.current_id = Some(get_anonymous_default_export_id());
-                                array_lit.visit_with(self);
-                                self.current_id = None;
-                            }
-                            // export default { name1, name2 };
-                            Expr::Object(object_lit) => {
-                                self.current_id = Some(get_anonymous_default_export_id());
-                                object_lit.visit_with(self);
-                                self.current_id = None;
+                    }
+                }
+            }
+            // const { bird } = LABELS.a || {}
+            Pat::Assign(AssignPat { left, .. }) => self.declare_aliases(left, paths),
+            // LABELS is never an array
+            _ => (),
+        }
+    }
+
+    fn declare_top_level_aliases(&mut self, module_items: &[ModuleItem]) {
+        for module_item in module_items {
+            match module_item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var_decl),
+                    ..
+                }))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                    for var_declarator in &var_decl.decls {
+                        if let Some(init) = &var_declarator.init {
+                            if let Some(paths) = self.get_label_paths(init) {
+                                self.declare_aliases(&var_declarator.name, paths);
                             }
-                            _ => (),
                         }
                     }
-                    _ => (),
-                },
-                ModuleItem::Stmt(stmt) => match stmt {
-                    Stmt::Decl(decl) => match decl {
-                        // class Foo {}
-                        Decl::Class(ClassDecl { ident, class, .. }) => {
-                            self.current_id = Some(ident.to_id());
-                            class.visit_with(self);
-                            self.current_id = None;
-                        }
-                        // function foo() {}
-                        Decl::Fn(FnDecl {
-                            ident, function, ..
-                        }) => {
-                            self.current_id = Some(ident.to_id());
-                            function.visit_with(self);
-                            self.current_id = None;
-                        }
-                        // const foo = init, bar = init;
-                        Decl::Var(var_decl) => {
-                            for var_decl in &var_decl.decls {
-                                match &var_decl.name {
-                                    Pat::Ident(BindingIdent { id, .. }) => {
-                                        self.current_id = Some(id.to_id());
-                                        var_decl.init.visit_with(self);
-                                        self.current_id = None;
-                                    }
-                                    _ => (),
-                                }
+                }
+                // namespace Admin { const L = LABELS.admin }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsModule(ts_module),
+                    ..
+                }))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) => {
+                    if let Some(TsModuleBlock { body, .. }) = get_namespace_block(ts_module) {
+                        self.declare_top_level_aliases(body);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    // Nothing is recorded here since there is no current_id yet, the functions
+    // are visited again with their symbols later.
+    fn declare_top_level_returns(&mut self, module: &Module) {
+        let aliases = self.aliases.clone();
+        for module_item in &module.body {
+            match module_item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+                | ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+                    Decl::Fn(fn_decl) => fn_decl.visit_with(self),
+                    Decl::Var(var_decl) => {
+                        for var_declarator in &var_decl.decls {
+                            if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+                                (&var_declarator.name, &var_declarator.init)
+                            {
+                                self.visit_function_declaration(id.to_id(), init);
                             }
                         }
-                        _ => (),
-                    },
+                    }
                     _ => (),
                 },
+                _ => (),
             }
         }
+        self.aliases = aliases;
     }
-}
 
-fn is_labels_obj(member_expr: &MemberExpr) -> bool {
-    let mut obj: &Box<Expr> = &member_expr.obj;
+    // `span` is the expression using the paths, like `LABELS.a.b`
+    fn record_usage(&mut self, paths: &LabelPaths, span: Span) {
+        if paths
+            .iter()
+            .any(|path| path.root == LabelRoot::Labels && path.path.is_empty())
+        {
+            self.whole_labels_used = true;
+        }
+        self.record_read_paths(paths);
+        let current_id = match &self.scope.current_id {
+            Some(current_id) => current_id.clone(),
+            None => return,
+        };
+        let translation_keys = self.get_translation_keys(paths);
+        for path in paths {
+            match path.root {
+                LabelRoot::Labels => (),
+                LabelRoot::Param(param) => {
+                    let reader = self.get_symbol_ref(&current_id);
+                    self.record_param_read(&current_id, reader, param, path.path.clone());
+                }
+            }
+        }
+        if translation_keys.is_empty() {
+            return;
+        }
+        let current_symbol = format!("{}{}", current_id.get_name(), self.scope.current_member);
+        self.record_occurrences(&current_symbol, &translation_keys, span);
+        if !self.label_usage.contains_key(&current_symbol) {
+            self.label_usage
+                .insert(current_symbol.clone(), HashSet::new());
+        }
+        self.label_usage
+            .entry(current_symbol)
+            .and_modify(|set| set.extend(translation_keys));
+    }
 
-    // find the ident by following the obj path, once found, check if it's sym is "LABELS"
-    loop {
-        match &**obj {
-            Expr::Member(member_expr) => {
-                obj = &member_expr.obj;
+    fn record_read_paths(&mut self, paths: &LabelPaths) {
+        self.read_paths.extend(
+            paths
+                .iter()
+                .filter(|path| path.root == LabelRoot::Labels && !path.path.is_empty())
+                .map(|path| path.path.clone()),
+        );
+    }
+
+    // the keys the paths starting from the LABELS lead to
+    fn get_translation_keys(&self, paths: &LabelPaths) -> HashSet<String> {
+        let mut translation_keys = HashSet::new();
+        for path in paths {
+            match path.root {
+                LabelRoot::Labels => {
+                    // passing the whole LABELS around isn't supported
+                    if path.path.is_empty() {
+                        continue;
+                    }
+                    // `LABELS.missing` leads to nothing
+                    if let Some(labels) = &self.labels {
+                        translation_keys.extend(
+                            labels
+                                .get_translation_keys_for_path(&path.path)
+                                .unwrap_or_default(),
+                        );
+                    }
+                }
+                LabelRoot::Param(_) => (),
             }
-            Expr::Ident(ident) => return ident.sym == "LABELS",
-            _ => return false,
         }
+        translation_keys
     }
-}
 
-pub fn get_labels_usage(
-    module: &Module,
-) -> anyhow::Result<Option<HashMap<String, HashSet<String>>>> {
-    let mut label_visitor = LabelVisitor::new();
-    module.visit_with(&mut label_visitor);
+    // The keys of the labels the callee paths end at, `LABELS.bird.toUpperCase()`
+    // calls a method of the label instead of the label itself.
+    fn get_called_translation_keys(&self, paths: &LabelPaths) -> HashSet<String> {
+        let labels = match &self.labels {
+            Some(labels) => labels,
+            None => return HashSet::new(),
+        };
+        paths
+            .iter()
+            .filter(|path| path.root == LabelRoot::Labels)
+            .flat_map(|path| labels.get_leaves_for_path(&path.path))
+            .filter_map(|leaf| leaf.get_translation_key())
+            .map(|translation_key| translation_key.to_owned())
+            .collect()
+    }
 
-    if let Some(labels) = label_visitor.labels {
-        let mut label_usage_visitor = LabelUsageVisitor::new(labels);
-        module.visit_with(&mut label_usage_visitor);
-        return Ok(Some(label_usage_visitor.label_usage));
+    // `LABELS.desc({ date })`, `paths` are the ones of the callee
+    fn record_call(&mut self, paths: &LabelPaths, call_expr: &CallExpr) {
+        let current_symbol = match self.scope.get_current_symbol() {
+            Some(current_symbol) => current_symbol,
+            None => return,
+        };
+        let label_call = LabelCall {
+            span: call_expr.span,
+            params: get_call_params(&call_expr.args),
+        };
+        let translation_keys = self.get_called_translation_keys(paths);
+        // `LABELS.a[type]()` is fine as long as one of them is lazy
+        if translation_keys
+            .iter()
+            .all(|translation_key| !self.lazy_keys.contains(translation_key))
+        {
+            for translation_key in sorted(&translation_keys) {
+                self.report(Diagnostic::new(
+                    Rule::CalledEagerLabel,
+                    format!(
+                        "\"{}\" isn't a lazy label, it's a string and can't be called",
+                        translation_key
+                    ),
+                    call_expr.span,
+                ));
+            }
+        }
+        for translation_key in translation_keys {
+            self.calls
+                .entry(current_symbol.clone())
+                .or_default()
+                .entry(translation_key)
+                .or_default()
+                .push(label_call.clone());
+        }
     }
 
-    Ok(None)
-}
+    // the top level functions are visited beforehand to find what they return,
+    // see `declare_top_level_returns`
+    fn report(&mut self, diagnostic: Diagnostic) {
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::test_utils;
+    // `{LABELS.desc}` or `<img alt={LABELS.desc} />` renders the function of a
+    // lazy label instead of calling it
+    fn check_rendered(&mut self, expr: &Expr) {
+        let translation_keys = match self.get_label_paths(expr) {
+            Some(paths) => self.get_translation_keys(&paths),
+            None => return,
+        };
+        if translation_keys.is_empty()
+            || !translation_keys
+                .iter()
+                .all(|translation_key| self.lazy_keys.contains(translation_key))
+        {
+            return;
+        }
+        for translation_key in sorted(&translation_keys) {
+            self.report(Diagnostic::new(
+                Rule::UncalledLazyLabel,
+                format!(
+                    "\"{}\" is a lazy label, it should be called with its parameters instead of being rendered",
+                    translation_key
+                ),
+                expr.span(),
+            ));
+        }
+    }
 
-    macro_rules! assert_label_usage {
-        ($code:expr, $($symbol_name:expr => $expected_keys:expr),* $(,)?) => {{
-            let module = test_utils::parse_module($code).unwrap();
-            let label_usage = get_labels_usage(&module).unwrap().unwrap();
+    fn record_occurrences(&mut self, symbol: &str, translation_keys: &HashSet<String>, span: Span) {
+        let occurrences = self.occurrences.entry(symbol.to_string()).or_default();
+        for translation_key in translation_keys {
+            let spans = occurrences.entry(translation_key.clone()).or_default();
+            if !spans.contains(&span) {
+                spans.push(span);
+            }
+        }
+    }
 
-            $(
-                let keys = label_usage.get($symbol_name).unwrap();
-                assert_eq!(keys.len(), $expected_keys.len(), "keys count mismatch");
-                for &expected_key in $expected_keys.iter() {
-                    assert!(keys.contains(expected_key), "missing key: {}", expected_key);
-                }
-            )*
-        }};
+    fn get_symbol_ref(&self, id: &SymbolId) -> SymbolRef {
+        SymbolRef {
+            module: self.module_path.to_string(),
+            symbol: id.get_name(),
+        }
     }
 
-    #[test]
-    fn simple() {
-        assert_label_usage!(
-            r#"
-            const LABELS = translate({
-                bird: "i18n.bird",
-                cat: "i18n.cat",
-                dog: "i18n.dog",
-            })
+    fn record_param_read(
+        &mut self,
+        id: &SymbolId,
+        reader: SymbolRef,
+        param: usize,
+        path: LabelPath,
+    ) {
+        self.summaries
+            .entry(id.clone())
+            .or_default()
+            .insert(ParamRead {
+                reader,
+                param,
+                path,
+            });
+    }
 
-            const Bird = () => {
-                return <div>{LABELS.bird}</div>
+    // `reader` reads `rest` of what's passed as `paths`, like `Bar` reads
+    // `b.bird` of `LABELS.a` in `<Bar labels={LABELS.a} />`. The occurrences
+    // of the readers of this module are where the paths are passed.
+    fn record_passed_usage(
+        &mut self,
+        reader: &SymbolRef,
+        paths: &LabelPaths,
+        rest: &[PathSegment],
+        span: Span,
+    ) {
+        let mut translation_keys = HashSet::new();
+        for path in paths {
+            let path = path.join(rest);
+            match path.root {
+                LabelRoot::Labels => {
+                    if path.path.is_empty() {
+                        continue;
+                    }
+                    self.read_paths.insert(path.path.clone());
+                    // the reader could be written for another LABELS with
+                    // more props, only the existing ones are counted
+                    if let Some(labels) = &self.labels {
+                        translation_keys.extend(
+                            labels
+                                .get_translation_keys_for_path(&path.path)
+                                .unwrap_or_default(),
+                        );
+                    }
+                }
+                // passed through the current function, so its callers know
+                LabelRoot::Param(param) => {
+                    if let Some(current_id) = self.scope.current_id.clone() {
+                        self.record_param_read(&current_id, reader.clone(), param, path.path);
+                    }
+                }
             }
-            const Cat = () => {
-                return <div>{LABELS.cat}</div>
+        }
+        if translation_keys.is_empty() {
+            return;
+        }
+        match self.context {
+            Some(_) if self.module_path != reader.module => {
+                self.external_usage
+                    .entry(reader.clone())
+                    .or_default()
+                    .extend(translation_keys);
             }
-            const Dog = () => {
-                return <div>{LABELS.dog}</div>
+            _ => {
+                self.record_occurrences(&reader.symbol, &translation_keys, span);
+                self.label_usage
+                    .entry(reader.symbol.clone())
+                    .or_default()
+                    .extend(translation_keys);
             }
-            "#,
-            "Bird" => ["i18n.bird"],
-            "Cat" => ["i18n.cat"],
-            "Dog" => ["i18n.dog"],
-        );
+        }
     }
 
-    #[test]
-    fn complex() {
-        assert_label_usage!(
-            r#"
-            const LABELS = translate({
-                title: "i18n.pet.party",
-                desc: ["i18n.pet.party.desc", "lazy"],
-                attendants: {
-                    bird: {
-                        name: "i18n.bird",
-                        desc: ["i18n.bird.desc", "lazy"],
-                        size: {
-                            [SIZE.samll]: "i18n.bird.small",
-                            [SIZE.large]: ["i18n.bird.large", "lazy"],
-                        },
-                    },
-                    cat: {
-                        name: "i18n.cat",
-                        desc: ["i18n.cat.desc", "lazy"],
-                        size: {
-                            [SIZE.samll]: "i18n.cat.small",
-                            [SIZE.large]: ["i18n.cat.large", "lazy"],
-                        },
-                    },
-                    dog: {
-                        name: "i18n.dog",
-                        desc: ["i18n.dog.desc", "lazy"],
-                        size: {
-                            [SIZE.samll]: "i18n.dog.small",
-                            [SIZE.large]: ["i18n.dog.large", "lazy"],
-                        },
-                    },
+    // What the function called `<Bar />` or `bar()` reads from its arguments,
+    // defined in this module or imported from another one.
+    fn get_callee_reads(&self, ident: &Ident) -> Option<ParamReads> {
+        let context = self.context?;
+        let id = ident.to_id();
+        if let Some(reads) = self.summaries.get(&SymbolId::Ident(id.clone())) {
+            return Some(reads.clone());
+        }
+        let (source, imported) = self.imports.get(&id)?;
+        let module_path = (context.resolve_import)(source)?;
+        context
+            .summaries
+            .get(&module_path)?
+            .get_export(imported)
+            .cloned()
+    }
+
+    // The params of the top level functions are where the parts of the LABELS
+    // passed by the callers start.
+    fn declare_top_level_params(&mut self, module: &Module) {
+        for module_item in &module.body {
+            match module_item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+                | ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+                    Decl::Fn(FnDecl { function, .. }) => self.declare_function_params(function),
+                    Decl::Var(var_decl) => {
+                        for var_declarator in &var_decl.decls {
+                            if let Some(init) = &var_declarator.init {
+                                self.declare_expr_params(init);
+                            }
+                        }
+                    }
+                    _ => (),
+                },
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(FnExpr { function, .. }),
+                    ..
+                })) => self.declare_function_params(function),
+                // exports.render = (props) => {}
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                    for (_, value) in get_commonjs_exports(expr) {
+                        self.declare_expr_params(value);
+                    }
                 }
-            })
+                _ => (),
+            }
+        }
+    }
 
-            const Header = (props) => {
-                const desc = LABELS.desc({ date: props.date })
-                return (
-                    <div>
-                        <h1>{LABELS.title}</h1>
-                        <p>{desc}</p>
-                    </div>
-                )   
+    // `function Bar(props) {}`, `const Bar = (props) => {}`, and the components
+    // wrapped like `const Bar = memo((props) => {})`
+    fn declare_expr_params(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Fn(FnExpr { function, .. }) => self.declare_function_params(function),
+            Expr::Arrow(ArrowExpr { params, .. }) => {
+                for (i, param) in params.iter().enumerate() {
+                    self.declare_aliases(param, HashSet::from([RootedPath::param(i)]));
+                }
+            }
+            Expr::Call(CallExpr { args, .. }) => {
+                if let Some(ExprOrSpread { spread: None, expr }) = args.first() {
+                    self.declare_expr_params(expr)
+                }
             }
+            Expr::Paren(ParenExpr { expr, .. }) => self.declare_expr_params(expr),
+            _ => (),
+        }
+    }
 
-            const Pet = (props) => {
-                return (
-                    <div>
-                        <h2>{props.name}</h2>
-                        <p>{props.desc}</p>
-                        <p>{props.size}</p>
-                    </div>
-                )
+    fn declare_function_params(&mut self, function: &Function) {
+        for (i, param) in function.params.iter().enumerate() {
+            self.declare_aliases(&param.pat, HashSet::from([RootedPath::param(i)]));
+        }
+    }
+
+    // The parts of a label expression which are not the labels themselves could
+    // still use the LABELS, like `LABELS.a[getType(LABELS.b)]` or the test of
+    // `cond ? LABELS.a : LABELS.b`.
+    fn visit_label_expr_parts(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Ident(_) => (),
+            Expr::Member(member_expr) => {
+                self.visit_label_expr_parts(&member_expr.obj);
+                member_expr.prop.visit_with(self);
+            }
+            Expr::OptChain(OptChainExpr { base, .. }) => match &**base {
+                OptChainBase::Member(member_expr) => {
+                    self.visit_label_expr_parts(&member_expr.obj);
+                    member_expr.prop.visit_with(self);
+                }
+                OptChainBase::Call(_) => (),
+            },
+            Expr::Paren(ParenExpr { expr, .. })
+            | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+            | Expr::TsAs(TsAsExpr { expr, .. })
+            | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. }) => self.visit_label_expr_parts(expr),
+            Expr::Cond(CondExpr {
+                test, cons, alt, ..
+            }) => {
+                test.visit_with(self);
+                self.visit_maybe_label_expr_parts(cons);
+                self.visit_maybe_label_expr_parts(alt);
+            }
+            Expr::Bin(BinExpr { left, right, .. }) => {
+                self.visit_maybe_label_expr_parts(left);
+                self.visit_maybe_label_expr_parts(right);
+            }
+            Expr::Seq(SeqExpr { exprs, .. }) => {
+                for (i, expr) in exprs.iter().enumerate() {
+                    match i == exprs.len() - 1 {
+                        true => self.visit_maybe_label_expr_parts(expr),
+                        false => expr.visit_with(self),
+                    }
+                }
             }
+            Expr::Call(CallExpr { args, .. }) => args.visit_with(self),
+            _ => expr.visit_children_with(self),
+        }
+    }
 
-            const PetParty = () => {
-                const pets = usePets();
-                return (
-                    <div>
-                        <Header />
-                        {pets.map((pet) => {
+    fn visit_maybe_label_expr_parts(&mut self, expr: &Expr) {
+        match self.get_label_paths(expr) {
+            Some(_) => self.visit_label_expr_parts(expr),
+            None => expr.visit_with(self),
+        }
+    }
+
+    // Visit a function and collect the parts of the LABELS it returns.
+    fn visit_function_like<N: VisitWith<Self>>(&mut self, node: &N) -> LabelPaths {
+        self.return_frames.push(HashSet::new());
+        node.visit_children_with(self);
+        self.return_frames.pop().unwrap_or_default()
+    }
+
+    fn declare_returns(&mut self, id: Id, returns: LabelPaths) {
+        if !returns.is_empty() {
+            self.returns.insert(id, returns);
+        }
+    }
+
+    fn collect_return(&mut self, expr: &Expr) {
+        if let Some(paths) = self.get_label_paths(expr) {
+            if let Some(frame) = self.return_frames.last_mut() {
+                // the params mean nothing to the callers
+                frame.extend(
+                    paths
+                        .into_iter()
+                        .filter(|path| path.root == LabelRoot::Labels),
+                );
+            }
+        }
+    }
+
+    // function getPetLabels() {}, const getPetLabels = () => {}
+    fn visit_function_declaration(&mut self, id: Id, init: &Expr) -> bool {
+        let returns = match init {
+            Expr::Fn(FnExpr { function, .. }) => self.visit_function_like(&**function),
+            Expr::Arrow(arrow_expr) => self.visit_function_like(arrow_expr),
+            _ => return false,
+        };
+        self.declare_returns(id, returns);
+        true
+    }
+
+    // The properties of the module's own LABELS whose labels are never read by
+    // the module, computed accesses like `LABELS.pets[type]` reach all the
+    // labels under `pets`. Nothing is reported once the LABELS is exported or
+    // used as a whole, since they could be read anywhere.
+    fn report_dead_properties(&mut self, module: &Module, properties: &[LabelProperty]) {
+        if self.whole_labels_used || get_exported_names(module).contains_key("LABELS") {
+            return;
+        }
+        let is_dead = |property: &LabelProperty| {
+            !self
+                .read_paths
+                .iter()
+                .any(|read_path| property.is_reached_by(read_path))
+        };
+        for property in properties {
+            // a dead object is reported through its named properties, or as a
+            // whole when it has none, like `size: { [SIZE.S]: "i18n.s" }`
+            let has_named_properties = properties.iter().any(|child| {
+                child.path.len() > property.path.len() && child.path.starts_with(&property.path)
+            });
+            if has_named_properties || !is_dead(property) {
+                continue;
+            }
+            // the whole `walk: { /* ... */ }` is removed if none of its labels
+            // is used, not only its properties
+            let removed = properties
+                .iter()
+                .filter(|ancestor| property.path.starts_with(&ancestor.path) && is_dead(ancestor))
+                .min_by_key(|ancestor| ancestor.path.len())
+                .unwrap_or(property);
+            self.diagnostics.push(
+                Diagnostic::new(
+                    Rule::DeadLabelProperty,
+                    format!(
+                        "LABELS.{} is never used in this module",
+                        property.path.join(".")
+                    ),
+                    property.span,
+                )
+                .with_fix(removed.span),
+            );
+        }
+    }
+
+    fn into_labels_usage(self, module: &Module) -> LabelsUsage {
+        LabelsUsage {
+            usage: self.label_usage,
+            occurrences: self.occurrences,
+            calls: self.calls,
+            diagnostics: self.diagnostics,
+            lazy_labels: self
+                .labels
+                .map(|labels| labels.get_lazy_labels())
+                .unwrap_or_default(),
+            external_usage: self.external_usage,
+            symbols: self.scope.into_symbols(module),
+        }
+    }
+
+    // The node may or may not be visited at runtime, e.g. the body of a loop,
+    // so afterwards a binding holds whatever it held before or after the node.
+    fn visit_maybe<N: VisitWith<Self>>(&mut self, node: &N) {
+        let before = self.aliases.clone();
+        node.visit_with(self);
+        self.aliases.join(before);
+    }
+}
+
+impl SymbolVisitor for LabelUsageVisitor<'_> {
+    fn symbol_scope(&mut self) -> &mut SymbolScope {
+        &mut self.scope
+    }
+
+    // const { bird, walk: { cat } } = LABELS, the bindings are top level aliases,
+    // the keys are used where the aliases are read unless they're exported
+    fn visit_alias_symbols(&mut self, pat: &Pat, init: &Expr) -> bool {
+        if self.get_label_paths(init).is_none() {
+            return false;
+        }
+        for ident in get_pat_binding_idents(pat) {
+            if let Some(paths) = self.aliases.get(&ident.to_id()).cloned() {
+                self.enter_ident_symbol(ident, SymbolKind::Variable);
+                if self.exported.contains(&*ident.sym) {
+                    self.record_usage(&paths, ident.span);
+                }
+                self.leave_symbol();
+            }
+        }
+        true
+    }
+}
+
+impl Visit for LabelUsageVisitor<'_> {
+    fn visit_expr(&mut self, node: &Expr) {
+        match self.get_label_paths(node) {
+            Some(paths) => {
+                self.record_usage(&paths, node.span());
+                self.visit_label_expr_parts(node);
+            }
+            None => node.visit_children_with(self),
+        }
+    }
+
+    // `{ bird }` with `const { bird } = LABELS`
+    fn visit_prop(&mut self, node: &Prop) {
+        match node {
+            Prop::Shorthand(ident) => {
+                if let Some(paths) = self.aliases.get(&ident.to_id()).cloned() {
+                    self.record_usage(&paths, ident.span);
+                }
+            }
+            _ => node.visit_children_with(self),
+        }
+    }
+
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        if let Some(init) = &node.init {
+            if let Some(paths) = self.get_label_paths(init) {
+                // it's used once the alias is used
+                self.declare_aliases(&node.name, paths);
+                node.name.visit_with(self);
+                self.visit_label_expr_parts(init);
+                return;
+            }
+            if let Pat::Ident(BindingIdent { id, .. }) = &node.name {
+                self.aliases.remove(&id.to_id());
+                if self.visit_function_declaration(id.to_id(), init) {
+                    return;
+                }
+            }
+        }
+        node.visit_children_with(self);
+    }
+
+    // `L = LABELS.b` after `let L = LABELS.a`
+    fn visit_assign_expr(&mut self, node: &AssignExpr) {
+        match (&node.left, node.op) {
+            (
+                AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent { id, .. })),
+                AssignOp::Assign,
+            ) => match self.get_label_paths(&node.right) {
+                Some(paths) => {
+                    self.aliases.assign(id.to_id(), paths);
+                    self.visit_label_expr_parts(&node.right);
+                }
+                None => {
+                    self.aliases.remove(&id.to_id());
+                    node.right.visit_with(self);
+                }
+            },
+            _ => node.visit_children_with(self),
+        }
+    }
+
+    fn visit_fn_decl(&mut self, node: &FnDecl) {
+        let returns = self.visit_function_like(&*node.function);
+        self.declare_returns(node.ident.to_id(), returns);
+    }
+
+    fn visit_function(&mut self, node: &Function) {
+        self.visit_function_like(node);
+    }
+
+    fn visit_arrow_expr(&mut self, node: &ArrowExpr) {
+        self.visit_function_like(node);
+    }
+
+    fn visit_return_stmt(&mut self, node: &ReturnStmt) {
+        if let Some(arg) = &node.arg {
+            self.collect_return(arg);
+        }
+        node.visit_children_with(self);
+    }
+
+    // the body of `() => LABELS.a` is returned
+    fn visit_block_stmt_or_expr(&mut self, node: &BlockStmtOrExpr) {
+        if let BlockStmtOrExpr::Expr(expr) = node {
+            self.collect_return(expr);
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_if_stmt(&mut self, node: &IfStmt) {
+        node.test.visit_with(self);
+        let before = self.aliases.clone();
+        node.cons.visit_with(self);
+        let after_cons = std::mem::replace(&mut self.aliases, before);
+        node.alt.visit_with(self);
+        self.aliases.join(after_cons);
+    }
+
+    fn visit_switch_stmt(&mut self, node: &SwitchStmt) {
+        node.discriminant.visit_with(self);
+        // every case can be skipped, a case falling through is just joined
+        let before = self.aliases.clone();
+        let mut after = before.clone();
+        for case in &node.cases {
+            self.aliases = before.clone();
+            case.visit_with(self);
+            after.join(self.aliases.clone());
+        }
+        self.aliases = after;
+    }
+
+    fn visit_try_stmt(&mut self, node: &TryStmt) {
+        self.visit_maybe(&node.block);
+        self.visit_maybe(&node.handler);
+        node.finalizer.visit_with(self);
+    }
+
+    // loops might not be run at all
+    fn visit_while_stmt(&mut self, node: &WhileStmt) {
+        node.test.visit_with(self);
+        self.visit_maybe(&node.body);
+    }
+
+    fn visit_for_stmt(&mut self, node: &ForStmt) {
+        node.init.visit_with(self);
+        node.test.visit_with(self);
+        let before = self.aliases.clone();
+        node.body.visit_with(self);
+        node.update.visit_with(self);
+        self.aliases.join(before);
+    }
+
+    fn visit_for_in_stmt(&mut self, node: &ForInStmt) {
+        node.right.visit_with(self);
+        node.left.visit_with(self);
+        self.visit_maybe(&node.body);
+    }
+
+    fn visit_for_of_stmt(&mut self, node: &ForOfStmt) {
+        node.right.visit_with(self);
+        node.left.visit_with(self);
+        self.visit_maybe(&node.body);
+    }
+
+    fn visit_jsx_element_child(&mut self, node: &JSXElementChild) {
+        if let JSXElementChild::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        }) = node
+        {
+            self.check_rendered(expr)
+        }
+        node.visit_children_with(self);
+    }
+
+    // `<Bar labels={LABELS.a} />`
+    fn visit_jsx_opening_element(&mut self, node: &JSXOpeningElement) {
+        // the attributes of the html elements are rendered as they are
+        match &node.name {
+            JSXElementName::Ident(ident) if ident.sym.starts_with(|c: char| c.is_lowercase()) => {
+                for attr in &node.attrs {
+                    if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                        value:
+                            Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                expr: JSXExpr::Expr(expr),
+                                ..
+                            })),
+                        ..
+                    }) = attr
+                    {
+                        self.check_rendered(expr)
+                    }
+                }
+            }
+            _ => (),
+        }
+        let reads = match &node.name {
+            JSXElementName::Ident(ident) => self.get_callee_reads(ident),
+            _ => None,
+        };
+        for read in reads.unwrap_or_default() {
+            for attr in &node.attrs {
+                if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value:
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(expr),
+                            ..
+                        })),
+                    ..
+                }) = attr
+                {
+                    let paths = self.get_label_paths(expr);
+                    if let (Some(paths), Some(rest)) = (paths, read.rest_for_prop(&name.sym)) {
+                        self.record_passed_usage(&read.reader, &paths, rest, expr.span())
+                    }
+                }
+            }
+        }
+        node.visit_children_with(self);
+    }
+
+    // `getBirdLabel(LABELS.a)` or `LABELS.desc({ date })`
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Callee::Expr(callee) = &node.callee {
+            if let Some(paths) = self.get_label_paths(callee) {
+                self.record_call(&paths, node);
+            }
+        }
+        let reads = match &node.callee {
+            Callee::Expr(callee) => match &**callee {
+                Expr::Ident(ident) => self.get_callee_reads(ident),
+                _ => None,
+            },
+            _ => None,
+        };
+        for read in reads.unwrap_or_default() {
+            for (i, arg) in node.args.iter().enumerate() {
+                if arg.spread.is_some() {
+                    break;
+                }
+                let paths = self.get_label_paths(&arg.expr);
+                if let (Some(paths), Some(rest)) = (paths, read.rest_for_arg(i)) {
+                    self.record_passed_usage(&read.reader, &paths, rest, arg.expr.span())
+                }
+            }
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_module(&mut self, node: &Module) {
+        if self.context.is_some() {
+            self.imports = collect_imports(node);
+            self.declare_top_level_params(node);
+        }
+        self.exported = get_exported_names(node).into_keys().collect();
+        // top level aliases and functions returning a part of the LABELS can be
+        // used before they're declared, e.g. in a function
+        self.declare_top_level_aliases(&node.body);
+        self.declare_top_level_returns(node);
+        // aliases may come from the functions, `const L = getPetLabels()`
+        self.declare_top_level_aliases(&node.body);
+
+        self.visit_top_level_symbols(node);
+    }
+}
+
+// `render`, `"on-error"` and `0` of `{ render() {}, "on-error": () => {}, 0: a }`,
+// computed keys can't be named.
+pub(crate) fn get_prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str) => Some(str.value.to_string()),
+        PropName::Num(num) => Some(num.value.to_string()),
+        PropName::BigInt(big_int) => Some(big_int.value.to_string()),
+        PropName::Computed(_) => None,
+    }
+}
+
+// `{ a: LABELS.a, b }` -> { "a": LABELS.a, "b": b }, None if some of the props
+// can't be named or destructured into bindings, like spreads and methods.
+pub(super) fn get_object_values(object_lit: &ObjectLit) -> Option<HashMap<String, Expr>> {
+    let mut values = HashMap::new();
+    for prop in &object_lit.props {
+        match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(KeyValueProp { key, value }) => {
+                    values.insert(get_prop_name(key)?, *value.clone());
+                }
+                Prop::Shorthand(ident) => {
+                    values.insert(ident.sym.to_string(), Expr::Ident(ident.clone()));
+                }
+                _ => return None,
+            },
+            PropOrSpread::Spread(_) => return None,
+        }
+    }
+    Some(values)
+}
+
+fn sorted(translation_keys: &HashSet<String>) -> Vec<String> {
+    let mut translation_keys: Vec<String> = translation_keys.iter().cloned().collect();
+    translation_keys.sort();
+    translation_keys
+}
+
+// `({ date, count: 1 })` -> ["count", "date"], `()` passes nothing. None if
+// the names can't be known, like `(params)` or `({ ...params })`.
+fn get_call_params(args: &[ExprOrSpread]) -> Option<Vec<String>> {
+    let mut params: Vec<String> = match args.first() {
+        None => vec![],
+        Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+            Expr::Object(object_lit) => get_object_values(object_lit)?.into_keys().collect(),
+            _ => return None,
+        },
+        Some(_) => return None,
+    };
+    params.sort();
+    Some(params)
+}
+
+// `const { a, b: [c, ...d], e = 1 } = init` binds `a`, `c`, `d` and `e`
+pub(super) fn get_pat_binding_idents(pat: &Pat) -> Vec<&Ident> {
+    match pat {
+        Pat::Ident(BindingIdent { id, .. }) => vec![id],
+        Pat::Array(ArrayPat { elems, .. }) => elems
+            .iter()
+            .flatten()
+            .flat_map(get_pat_binding_idents)
+            .collect(),
+        Pat::Object(ObjectPat { props, .. }) => props
+            .iter()
+            .flat_map(|prop| match prop {
+                ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => {
+                    get_pat_binding_idents(value)
+                }
+                ObjectPatProp::Assign(AssignPatProp { key, .. }) => vec![&key.id],
+                ObjectPatProp::Rest(RestPat { arg, .. }) => get_pat_binding_idents(arg),
+            })
+            .collect(),
+        Pat::Assign(AssignPat { left, .. }) => get_pat_binding_idents(left),
+        Pat::Rest(RestPat { arg, .. }) => get_pat_binding_idents(arg),
+        Pat::Invalid(_) | Pat::Expr(_) => vec![],
+    }
+}
+
+// `const Foo = () => {}` is a function, `const Foo = class {}` is a class and
+// anything else is a variable.
+pub(super) fn get_value_kind(value: Option<&Expr>) -> SymbolKind {
+    match value {
+        Some(Expr::Fn(_) | Expr::Arrow(_)) => SymbolKind::Function,
+        Some(Expr::Class(_)) => SymbolKind::Class,
+        // const Foo = memo(() => {})
+        Some(Expr::Call(CallExpr { args, .. })) => match args.first() {
+            Some(ExprOrSpread { spread: None, expr }) => match get_value_kind(Some(expr)) {
+                SymbolKind::Function => SymbolKind::Function,
+                _ => SymbolKind::Variable,
+            },
+            _ => SymbolKind::Variable,
+        },
+        Some(
+            Expr::Paren(ParenExpr { expr, .. })
+            | Expr::TsAs(TsAsExpr { expr, .. })
+            | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. }),
+        ) => get_value_kind(Some(expr)),
+        _ => SymbolKind::Variable,
+    }
+}
+
+// `namespace Admin.Users { /* … */ }` -> the block of `Users`
+fn get_namespace_block(ts_module: &TsModuleDecl) -> Option<&TsModuleBlock> {
+    if ts_module.declare {
+        return None;
+    }
+    let mut body = ts_module.body.as_ref()?;
+    loop {
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => return Some(block),
+            TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl { body: inner, .. }) => body = inner,
+        }
+    }
+}
+
+fn get_module_export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(str) => str.value.to_string(),
+    }
+}
+
+// exported name -> local symbol
+fn collect_exports(module: &Module) -> HashMap<String, String> {
+    let mut exports = HashMap::new();
+    for module_item in &module.body {
+        match module_item {
+            ModuleItem::ModuleDecl(module_decl) => match module_decl {
+                ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => match decl {
+                    Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                        exports.insert(ident.sym.to_string(), ident.sym.to_string());
+                    }
+                    Decl::TsEnum(ts_enum) => {
+                        exports.insert(ts_enum.id.sym.to_string(), ts_enum.id.sym.to_string());
+                    }
+                    Decl::Var(var_decl) => {
+                        for var_declarator in &var_decl.decls {
+                            for ident in get_pat_binding_idents(&var_declarator.name) {
+                                exports.insert(ident.sym.to_string(), ident.sym.to_string());
+                            }
+                        }
+                    }
+                    _ => (),
+                },
+                // export { Foo, Bar as Baz }
+                ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                }) => {
+                    for specifier in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig, exported, ..
+                        }) = specifier
+                        {
+                            let orig = get_module_export_name(orig);
+                            let exported = match exported {
+                                Some(exported) => get_module_export_name(exported),
+                                None => orig.clone(),
+                            };
+                            exports.insert(exported, orig);
+                        }
+                    }
+                }
+                ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => {
+                    let ident = match decl {
+                        DefaultDecl::Class(ClassExpr { ident, .. }) => ident,
+                        DefaultDecl::Fn(FnExpr { ident, .. }) => ident,
+                        DefaultDecl::TsInterfaceDecl(_) => continue,
+                    };
+                    let local = match ident {
+                        Some(ident) => ident.sym.to_string(),
+                        None => ANONYMOUS_DEFAULT_EXPORT_NAME.to_string(),
+                    };
+                    exports.insert("default".to_string(), local);
+                }
+                // export = Foo
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. })
+                | ModuleDecl::TsExportAssignment(TsExportAssignment { expr, .. }) => {
+                    let local = match &**expr {
+                        // export default Foo
+                        Expr::Ident(ident) => ident.sym.to_string(),
+                        _ => ANONYMOUS_DEFAULT_EXPORT_NAME.to_string(),
+                    };
+                    exports.insert("default".to_string(), local);
+                }
+                _ => (),
+            },
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                for (exported, local) in get_commonjs_export_names(expr) {
+                    exports.insert(exported, local);
+                }
+            }
+            _ => (),
+        }
+    }
+    exports
+}
+
+// `module.exports` and `exports`
+fn is_commonjs_exports_object(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(ident) => ident.sym == "exports",
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => {
+            prop.sym == "exports" && matches!(&**obj, Expr::Ident(ident) if ident.sym == "module")
+        }
+        _ => false,
+    }
+}
+
+// `module.exports = value` -> (None, value), `exports.x = value` and
+// `module.exports.x = value` -> (Some("x"), value)
+pub(super) fn get_commonjs_export(expr: &Expr) -> Option<(Option<String>, &Expr)> {
+    let (left, right) = match expr {
+        Expr::Assign(AssignExpr {
+            op: AssignOp::Assign,
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(member_expr)),
+            right,
+            ..
+        }) => (member_expr, &**right),
+        _ => return None,
+    };
+    if is_commonjs_exports_object(&left.obj) {
+        let name = match &left.prop {
+            MemberProp::Ident(ident) => ident.sym.to_string(),
+            MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+                _ => return None,
+            },
+            MemberProp::PrivateName(_) => return None,
+        };
+        return Some((Some(name), right));
+    }
+    match is_commonjs_exports_object(&Expr::Member(left.clone())) {
+        true => Some((None, right)),
+        false => None,
+    }
+}
+
+pub(super) fn is_commonjs_export(expr: &Expr) -> bool {
+    get_commonjs_export(expr).is_some()
+}
+
+// The values exported by a CommonJS assignment, which aren't exported by their
+// local names, `module.exports = { render: () => {}, Foo }` -> [(render, ..)]
+// and `module.exports = () => {}` -> [(None, ..)].
+pub(super) fn get_commonjs_exports(expr: &Expr) -> Vec<(Option<String>, &Expr)> {
+    match get_commonjs_export(expr) {
+        Some((_, Expr::Ident(_))) | None => vec![],
+        Some((None, Expr::Object(object_lit))) => object_lit
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(KeyValueProp { key, value }) => match &**value {
+                        Expr::Ident(_) => None,
+                        value => Some((Some(get_prop_name(key)?), value)),
+                    },
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect(),
+        Some((exported, value)) => vec![(exported, value)],
+    }
+}
+
+// `exports.render = () => { /* … */ }` has no local binding, qualifying it
+// keeps it apart from a top-level `render` declared next to it.
+pub(super) fn get_commonjs_member_name(exported: &str) -> String {
+    format!("module.exports.{}", exported)
+}
+
+// exported name -> local symbol of a CommonJS assignment, `module.exports = Foo`
+// is imported as the default export.
+fn get_commonjs_export_names(expr: &Expr) -> Vec<(String, String)> {
+    let (exported, value) = match get_commonjs_export(expr) {
+        Some(export) => export,
+        None => return vec![],
+    };
+    match (exported, value) {
+        (Some(exported), Expr::Ident(ident)) => vec![(exported, ident.sym.to_string())],
+        (Some(exported), _) => vec![(exported.clone(), get_commonjs_member_name(&exported))],
+        (None, Expr::Ident(ident)) => vec![("default".to_string(), ident.sym.to_string())],
+        (None, Expr::Object(object_lit)) => object_lit
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::Shorthand(ident) => Some((ident.sym.to_string(), ident.sym.to_string())),
+                    Prop::Method(MethodProp { key, .. }) => {
+                        let exported = get_prop_name(key)?;
+                        let local = get_commonjs_member_name(&exported);
+                        Some((exported, local))
+                    }
+                    Prop::KeyValue(KeyValueProp { key, value }) => {
+                        let exported = get_prop_name(key)?;
+                        match &**value {
+                            Expr::Ident(ident) => Some((exported, ident.sym.to_string())),
+                            _ => {
+                                let local = get_commonjs_member_name(&exported);
+                                Some((exported, local))
+                            }
+                        }
+                    }
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect(),
+        (None, _) => vec![(
+            "default".to_string(),
+            ANONYMOUS_DEFAULT_EXPORT_NAME.to_string(),
+        )],
+    }
+}
+
+// `require("./Bar")`
+fn get_require_source(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => match (&**callee, args.as_slice()) {
+            (Expr::Ident(ident), [ExprOrSpread { spread: None, expr }])
+                if ident.sym == "require" =>
+            {
+                match &**expr {
+                    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// local binding -> (import source, imported name), from
+// `import Bar from "./Bar"`, `import { Bar as Baz } from "./Bar"`,
+// `const Bar = require("./Bar")` and `const { Bar: Baz } = require("./Bar")`
+fn collect_imports(module: &Module) -> HashMap<Id, (String, String)> {
+    let mut imports = HashMap::new();
+    for module_item in &module.body {
+        match module_item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                specifiers, src, ..
+            })) => {
+                for specifier in specifiers {
+                    let (local, imported) = match specifier {
+                        ImportSpecifier::Named(ImportNamedSpecifier {
+                            local, imported, ..
+                        }) => match imported {
+                            Some(imported) => (local, get_module_export_name(imported)),
+                            None => (local, local.sym.to_string()),
+                        },
+                        ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                            (local, "default".to_string())
+                        }
+                        ImportSpecifier::Namespace(_) => continue,
+                    };
+                    imports.insert(local.to_id(), (src.value.to_string(), imported));
+                }
+            }
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                for var_declarator in &var_decl.decls {
+                    let init = match &var_declarator.init {
+                        Some(init) => init,
+                        None => continue,
+                    };
+                    // const Bar = require("./Bar").Bar
+                    let (source, imported) = match &**init {
+                        Expr::Member(MemberExpr {
+                            obj,
+                            prop: MemberProp::Ident(prop),
+                            ..
+                        }) => (get_require_source(obj), Some(prop.sym.to_string())),
+                        init => (get_require_source(init), None),
+                    };
+                    let source = match source {
+                        Some(source) => source,
+                        None => continue,
+                    };
+                    match (&var_declarator.name, imported) {
+                        (Pat::Ident(BindingIdent { id, .. }), imported) => {
+                            let imported = imported.unwrap_or_else(|| "default".to_string());
+                            imports.insert(id.to_id(), (source, imported));
+                        }
+                        (Pat::Object(ObjectPat { props, .. }), None) => {
+                            for prop in props {
+                                let (local, imported) = match prop {
+                                    ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                                        match (&**value, get_prop_name(key)) {
+                                            (
+                                                Pat::Ident(BindingIdent { id, .. }),
+                                                Some(imported),
+                                            ) => (id, imported),
+                                            _ => continue,
+                                        }
+                                    }
+                                    ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+                                        (&key.id, key.sym.to_string())
+                                    }
+                                    ObjectPatProp::Rest(_) => continue,
+                                };
+                                imports.insert(local.to_id(), (source.clone(), imported));
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    imports
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Options {
+    // Report the members of classes and objects as their own symbols, like
+    // `Foo.render` for static members, `Foo#handleClick` for instance members
+    // and `handlers.onError` for object props, instead of `Foo` and `handlers`.
+    pub qualified_symbols: bool,
+}
+
+// local symbol -> the names it's exported as, e.g. `Foo` is exported as
+// `default` and `Bar` with `export { Foo as default, Foo as Bar }`
+pub(super) fn get_exported_names(module: &Module) -> HashMap<String, Vec<String>> {
+    let mut exported_names: HashMap<String, Vec<String>> = HashMap::new();
+    for (exported, local) in collect_exports(module) {
+        exported_names.entry(local).or_default().push(exported);
+    }
+    for names in exported_names.values_mut() {
+        names.sort();
+    }
+    exported_names
+}
+
+pub struct LabelsUsage {
+    // symbol -> translation keys used by the symbol
+    pub usage: HashMap<String, HashSet<String>>,
+
+    // symbol -> translation key -> the expressions using the key
+    pub occurrences: HashMap<String, HashMap<String, Vec<Span>>>,
+
+    // symbol -> translation key -> the calls of the key, like `LABELS.desc({ date })`
+    pub calls: HashMap<String, HashMap<String, Vec<LabelCall>>>,
+
+    // the misuses of the labels, like rendering a lazy label without calling it
+    pub diagnostics: Vec<Diagnostic>,
+
+    // lazy labels defined in the `LABELS`, keyed by their translation keys
+    pub lazy_labels: HashMap<String, LazyLabel>,
+
+    // translation keys read by the symbols of other modules, which receive a
+    // part of the LABELS from the symbols of this module
+    pub external_usage: HashMap<SymbolRef, HashSet<String>>,
+
+    // name -> the symbols of the module, members included
+    pub symbols: HashMap<String, Symbol>,
+}
+
+fn get_labels(module: &Module) -> anyhow::Result<Option<LABELS>> {
+    Ok(visit_labels(module)?.labels)
+}
+
+fn visit_labels(module: &Module) -> anyhow::Result<LabelVisitor> {
+    let mut label_visitor = LabelVisitor::new();
+    label_visitor.visit_module(module)?;
+    Ok(label_visitor)
+}
+
+pub fn get_labels_usage(
+    module: &Module,
+    module_path: &str,
+    options: Options,
+) -> anyhow::Result<Option<LabelsUsage>> {
+    let label_visitor = visit_labels(module)?;
+    if let Some(labels) = label_visitor.labels {
+        let mut label_usage_visitor =
+            LabelUsageVisitor::new(Some(labels), module_path, None, options);
+        module.visit_with(&mut label_usage_visitor);
+        label_usage_visitor
+            .diagnostics
+            .extend(label_visitor.diagnostics);
+        label_usage_visitor.report_dead_properties(module, &label_visitor.properties);
+        return Ok(Some(label_usage_visitor.into_labels_usage(module)));
+    }
+
+    Ok(None)
+}
+
+fn visit_following_props<'a>(
+    module: &Module,
+    labels: Option<LABELS>,
+    context: &'a ModuleContext<'a>,
+    options: Options,
+) -> LabelUsageVisitor<'a> {
+    let visit = |summaries: &HashMap<SymbolId, ParamReads>| {
+        let mut label_usage_visitor =
+            LabelUsageVisitor::new(labels.clone(), context.module_path, Some(context), options);
+        label_usage_visitor.summaries = summaries.clone();
+        module.visit_with(&mut label_usage_visitor);
+        label_usage_visitor
+    };
+    // see `MAX_SUMMARY_ROUNDS`
+    let mut summaries = HashMap::new();
+    let mut label_usage_visitor = visit(&summaries);
+    for _ in 1..MAX_SUMMARY_ROUNDS {
+        if label_usage_visitor.summaries == summaries {
+            break;
+        }
+        summaries = std::mem::take(&mut label_usage_visitor.summaries);
+        label_usage_visitor = visit(&summaries);
+    }
+    label_usage_visitor
+}
+
+// Like `get_labels_usage`, but the parts of the LABELS passed to other
+// functions are followed, so the keys they read are attributed to them too.
+pub fn get_labels_usage_following_props(
+    module: &Module,
+    context: &ModuleContext,
+    options: Options,
+) -> anyhow::Result<Option<LabelsUsage>> {
+    // the imported LABELS are checked by the module defining them
+    let label_visitor = visit_labels(module)?;
+    let labels = match label_visitor.labels {
+        Some(labels) => Some(labels),
+        None => get_imported_labels(module, context),
+    };
+    if let Some(labels) = labels {
+        let mut label_usage_visitor = visit_following_props(module, Some(labels), context, options);
+        label_usage_visitor
+            .diagnostics
+            .extend(label_visitor.diagnostics);
+        label_usage_visitor.report_dead_properties(module, &label_visitor.properties);
+        return Ok(Some(label_usage_visitor.into_labels_usage(module)));
+    }
+
+    Ok(None)
+}
+
+// `import { LABELS } from "./labels"` or `const { LABELS } = require("./labels")`
+fn get_imported_labels(module: &Module, context: &ModuleContext) -> Option<LABELS> {
+    collect_imports(module)
+        .into_iter()
+        .find_map(|(local, (source, imported))| {
+            if local.0 != "LABELS" {
+                return None;
+            }
+            let module_path = (context.resolve_import)(&source)?;
+            context
+                .summaries
+                .get(&module_path)?
+                .get_exported_labels(&imported)
+                .cloned()
+        })
+}
+
+// What the top level functions of the module read from their arguments.
+pub fn summarize_params(module: &Module, context: &ModuleContext) -> ModuleSummary {
+    // the summaries are keyed by the top level symbols anyway
+    let label_usage_visitor = visit_following_props(module, None, context, Options::default());
+    let functions = label_usage_visitor
+        .summaries
+        .into_iter()
+        .map(|(id, reads)| (id.get_name(), reads))
+        .collect();
+    // the malformed LABELS are reported when the usage of the module is collected
+    let labels = get_labels(module).ok().flatten();
+    ModuleSummary::new(functions, collect_exports(module), labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::params::ProjectSummaries;
+    use super::*;
+    use crate::test_utils;
+
+    macro_rules! assert_label_usage {
+        ($code:expr, $($symbol_name:expr => $expected_keys:expr),* $(,)?) => {{
+            let module = test_utils::parse_module($code).unwrap();
+            let label_usage = get_labels_usage(&module, "test.js", Options::default()).unwrap().unwrap().usage;
+
+            $(
+                let keys = label_usage.get($symbol_name).unwrap();
+                assert_eq!(keys.len(), $expected_keys.len(), "keys count mismatch");
+                for &expected_key in $expected_keys.iter() {
+                    assert!(keys.contains(expected_key), "missing key: {}", expected_key);
+                }
+            )*
+        }};
+    }
+
+    #[test]
+    fn simple() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                dog: "i18n.dog",
+            })
+
+            const Bird = () => {
+                return <div>{LABELS.bird}</div>
+            }
+            const Cat = () => {
+                return <div>{LABELS.cat}</div>
+            }
+            const Dog = () => {
+                return <div>{LABELS.dog}</div>
+            }
+            "#,
+            "Bird" => ["i18n.bird"],
+            "Cat" => ["i18n.cat"],
+            "Dog" => ["i18n.dog"],
+        );
+    }
+
+    #[test]
+    fn complex() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                title: "i18n.pet.party",
+                desc: ["i18n.pet.party.desc", "lazy"],
+                attendants: {
+                    bird: {
+                        name: "i18n.bird",
+                        desc: ["i18n.bird.desc", "lazy"],
+                        size: {
+                            [SIZE.samll]: "i18n.bird.small",
+                            [SIZE.large]: ["i18n.bird.large", "lazy"],
+                        },
+                    },
+                    cat: {
+                        name: "i18n.cat",
+                        desc: ["i18n.cat.desc", "lazy"],
+                        size: {
+                            [SIZE.samll]: "i18n.cat.small",
+                            [SIZE.large]: ["i18n.cat.large", "lazy"],
+                        },
+                    },
+                    dog: {
+                        name: "i18n.dog",
+                        desc: ["i18n.dog.desc", "lazy"],
+                        size: {
+                            [SIZE.samll]: "i18n.dog.small",
+                            [SIZE.large]: ["i18n.dog.large", "lazy"],
+                        },
+                    },
+                }
+            })
+
+            const Header = (props) => {
+                const desc = LABELS.desc({ date: props.date })
+                return (
+                    <div>
+                        <h1>{LABELS.title}</h1>
+                        <p>{desc}</p>
+                    </div>
+                )   
+            }
+
+            const Pet = (props) => {
+                return (
+                    <div>
+                        <h2>{props.name}</h2>
+                        <p>{props.desc}</p>
+                        <p>{props.size}</p>
+                    </div>
+                )
+            }
+
+            const PetParty = () => {
+                const pets = usePets();
+                return (
+                    <div>
+                        <Header />
+                        {pets.map((pet) => {
                             <Pet 
                                 name={LABELS.attendants[pet.type].name}
                                 desc={LABELS.attendants[pet.type].desc({ name: pet.name })}
@@ -402,22 +1809,1021 @@ mod tests {
                 )
             }
             "#,
-            "Header" => ["i18n.pet.party", "i18n.pet.party.desc"],
-            "PetParty" => [
-                "i18n.bird",
-                "i18n.bird.desc",
-                "i18n.bird.small",
-                "i18n.bird.large",
-                "i18n.cat",
-                "i18n.cat.desc",
-                "i18n.cat.small",
-                "i18n.cat.large",
-                "i18n.dog",
-                "i18n.dog.desc",
-                "i18n.dog.small",
-                "i18n.dog.large",
+            "Header" => ["i18n.pet.party", "i18n.pet.party.desc"],
+            "PetParty" => [
+                "i18n.bird",
+                "i18n.bird.desc",
+                "i18n.bird.small",
+                "i18n.bird.large",
+                "i18n.cat",
+                "i18n.cat.desc",
+                "i18n.cat.small",
+                "i18n.cat.large",
+                "i18n.dog",
+                "i18n.dog.desc",
+                "i18n.dog.small",
+                "i18n.dog.large",
+            ]
+        )
+    }
+
+    #[test]
+    fn key_patterns() {
+        assert_label_usage!(
+            r#"
+            const PREFIX = "i18n";
+            const LABELS = translate({
+                bird: `${PREFIX}.bird`,
+                cat: PREFIX + ".cat",
+                title: `${PREFIX}.${type}.title`,
+            })
+
+            const Bird = () => <div>{LABELS.bird}</div>
+            const Cat = () => <div>{LABELS.cat}</div>
+            const Title = () => <div>{LABELS.title}</div>
+            "#,
+            "Bird" => ["i18n.bird"],
+            "Cat" => ["i18n.cat"],
+            "Title" => ["i18n.*.title"],
+        );
+    }
+
+    #[test]
+    fn lazy_labels() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                title: "i18n.title",
+                desc: ["i18n.desc", "lazy"],
+                count: ["i18n.count", "lazy", { plural: true, description: "number of pets" }],
+            })
+
+            const Foo = () => <div>{LABELS.title}{LABELS.desc({ date })}</div>
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "test.js", Options::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(labels_usage.lazy_labels.len(), 2);
+        assert!(!labels_usage.lazy_labels.get("i18n.desc").unwrap().plural);
+        let count = labels_usage.lazy_labels.get("i18n.count").unwrap();
+        assert!(count.plural);
+        assert_eq!(count.description.as_deref(), Some("number of pets"));
+        assert_eq!(
+            labels_usage.calls["Foo"]["i18n.desc"]
+                .iter()
+                .map(|label_call| label_call.params.clone())
+                .collect::<Vec<_>>(),
+            [Some(vec!["date".to_string()])]
+        );
+        assert!(!labels_usage.calls["Foo"].contains_key("i18n.title"));
+    }
+
+    #[test]
+    fn lazy_label_misuses() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                title: "i18n.title",
+                desc: ["i18n.desc", "lazy"],
+                pets: {
+                    cat: "i18n.cat",
+                    dog: ["i18n.dog", "lazy"],
+                },
+            })
+
+            const Foo = ({ type }) => (
+                <Bar render={LABELS.desc} title={LABELS.title}>
+                    <img alt={LABELS.desc} />
+                    {LABELS.desc}
+                    {LABELS.title()}
+                    {LABELS.desc({ date })}
+                    {LABELS.pets[type]()}
+                    {LABELS.title.toUpperCase()}
+                    {LABELS.pets.cat.replace("a", "b")}
+                    {LABELS.pets[type].trim()}
+                </Bar>
+            )
+            "#,
+        )
+        .unwrap();
+        let diagnostics = get_labels_usage(&module, "test.js", Options::default())
+            .unwrap()
+            .unwrap()
+            .diagnostics;
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    Rule::UncalledLazyLabel,
+                    r#""i18n.desc" is a lazy label, it should be called with its parameters instead of being rendered"#
+                ),
+                (
+                    Rule::UncalledLazyLabel,
+                    r#""i18n.desc" is a lazy label, it should be called with its parameters instead of being rendered"#
+                ),
+                (
+                    Rule::CalledEagerLabel,
+                    r#""i18n.title" isn't a lazy label, it's a string and can't be called"#
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn dead_properties() {
+        let dead_properties = |input: &str| -> Vec<String> {
+            let module = test_utils::parse_module(input).unwrap();
+            get_labels_usage(&module, "test.js", Options::default())
+                .unwrap()
+                .unwrap()
+                .diagnostics
+                .into_iter()
+                .filter(|diagnostic| diagnostic.rule == Rule::DeadLabelProperty)
+                .map(|diagnostic| diagnostic.message)
+                .collect()
+        };
+        let labels = r#"
+            const LABELS = translate({
+                title: "i18n.title",
+                desc: ["i18n.desc", "lazy"],
+                pets: {
+                    cat: "i18n.cat",
+                    dog: "i18n.dog",
+                },
+                walk: {
+                    cat: "i18n.walk.cat",
+                    dog: "i18n.walk.dog",
+                },
+            })
+        "#;
+        assert_eq!(
+            dead_properties(&format!(
+                "{}{}",
+                labels,
+                "const Foo = ({ type }) => <div>{LABELS.desc({ date })}{LABELS.pets[type]}</div>"
+            )),
+            [
+                "LABELS.title is never used in this module",
+                "LABELS.walk.cat is never used in this module",
+                "LABELS.walk.dog is never used in this module",
+            ]
+        );
+        assert!(dead_properties(&format!(
+            "{}{}",
+            labels, "const Foo = () => <Bar labels={LABELS} />"
+        ))
+        .is_empty());
+        assert!(dead_properties(&format!("{}{}", labels, "export { LABELS }")).is_empty());
+        // `b` shares its key with `a`, but is never read
+        assert_eq!(
+            dead_properties(
+                r#"
+                const LABELS = translate({
+                    a: "i18n.x",
+                    b: "i18n.x",
+                })
+                const Foo = () => <div>{LABELS.a}</div>
+                "#
+            ),
+            ["LABELS.b is never used in this module"]
+        );
+        assert_eq!(
+            dead_properties(
+                r#"
+                const LABELS = translate({
+                    used: "i18n.u",
+                    size: {
+                        [SIZE.S]: "i18n.s",
+                        [SIZE.L]: "i18n.l",
+                    },
+                })
+                const Foo = () => <div>{LABELS.used}</div>
+                "#
+            ),
+            ["LABELS.size is never used in this module"]
+        );
+    }
+
+    #[test]
+    fn optional_chaining() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                walk: {
+                    cat: "i18n.cat",
+                    dog: "i18n.dog",
+                },
+                desc: ["i18n.desc", "lazy"],
+            })
+
+            const Bird = () => <div>{LABELS?.bird}</div>
+            const Cat = () => <div>{LABELS.walk?.cat}</div>
+            const Dog = () => <div>{LABELS?.walk?.["dog"]}</div>
+            const Desc = () => <div>{LABELS.desc?.({ date })}</div>
+            "#,
+            "Bird" => ["i18n.bird"],
+            "Cat" => ["i18n.cat"],
+            "Dog" => ["i18n.dog"],
+            "Desc" => ["i18n.desc"],
+        );
+    }
+
+    #[test]
+    fn destructuring() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                attendants: {
+                    dog: "i18n.dog",
+                    fox: "i18n.fox",
+                },
+            })
+
+            const Pets = () => {
+                const { bird, cat: kitty } = LABELS
+                return <div>{bird}{kitty}</div>
+            }
+            const Dog = () => {
+                const { attendants: { dog } } = LABELS
+                return <div>{dog}</div>
+            }
+            const Unused = () => {
+                const { bird } = LABELS
+                return null
+            }
+            const Rest = () => {
+                const { dog, ...rest } = LABELS.attendants
+                return <Foo labels={rest} />
+            }
+            const Shorthand = () => {
+                const { fox } = LABELS.attendants
+                return useMemo(() => ({ fox }), [])
+            }
+            "#,
+            "Pets" => ["i18n.bird", "i18n.cat"],
+            "Dog" => ["i18n.dog"],
+            "Rest" => ["i18n.dog", "i18n.fox"],
+            "Shorthand" => ["i18n.fox"],
+        );
+    }
+
+    #[test]
+    fn missing_labels() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                attendants: {
+                    cat: "i18n.cat",
+                },
+            })
+
+            const Foo = () => <div>{LABELS.missing}{LABELS.attendants.dog}{LABELS.bird}</div>
+            const Bar = () => {
+                const { fish } = LABELS
+                return <div>{fish}{LABELS.missing.desc()}</div>
+            }
+            "#,
+            "Foo" => ["i18n.bird"],
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        let code = r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                attendants: {
+                    cat: "i18n.cat",
+                    dog: "i18n.dog",
+                },
+            })
+
+            const { bird } = LABELS
+            const ATTENDANTS = LABELS.attendants
+
+            const Bird = () => <div>{bird}</div>
+            const Cat = () => {
+                const L = LABELS.attendants
+                return <div>{L.cat}</div>
+            }
+            const Dog = () => <div>{ATTENDANTS.dog}</div>
+            export const { bird: EXPORTED_BIRD } = LABELS
+            "#;
+        assert_label_usage!(
+            code,
+            "EXPORTED_BIRD" => ["i18n.bird"],
+            "Bird" => ["i18n.bird"],
+            "Cat" => ["i18n.cat"],
+            "Dog" => ["i18n.dog"],
+        );
+        // the aliases only use what's read through them
+        let module = test_utils::parse_module(code).unwrap();
+        let label_usage = get_labels_usage(&module, "test.js", Options::default())
+            .unwrap()
+            .unwrap()
+            .usage;
+        assert!(!label_usage.contains_key("ATTENDANTS"));
+        assert!(!label_usage.contains_key("bird"));
+    }
+
+    #[test]
+    fn flow_sensitive_aliases() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                attendants: {
+                    cat: {
+                        name: "i18n.cat.name",
+                        desc: "i18n.cat.desc",
+                    },
+                    dog: {
+                        name: "i18n.dog.name",
+                        desc: "i18n.dog.desc",
+                    },
+                },
+            })
+
+            const getPetLabels = (type) => LABELS.attendants[type]
+            function getCatLabels() {
+                return LABELS.attendants.cat
+            }
+
+            const PetName = ({ type }) => {
+                const pet = LABELS.attendants[type]
+                return <div>{pet.name}</div>
+            }
+            const CatOrDog = ({ isCat }) => {
+                const catOrDog = isCat ? LABELS.attendants.cat : LABELS.attendants.dog
+                return <div>{catOrDog.desc}</div>
+            }
+            const Reassigned = () => {
+                let reassigned = LABELS.attendants.cat
+                reassigned = LABELS.attendants.dog
+                return <div>{reassigned.name}</div>
+            }
+            const Branches = ({ isCat }) => {
+                let branched = LABELS.bird
+                if (isCat) {
+                    branched = LABELS.attendants.cat.name
+                }
+                return <div>{branched}</div>
+            }
+            const Helper = ({ type }) => <div>{getPetLabels(type).desc}</div>
+            const HelperAlias = () => {
+                const cat = getCatLabels()
+                return <div>{cat.name}</div>
+            }
+            "#,
+            "getPetLabels" => ["i18n.cat.name", "i18n.cat.desc", "i18n.dog.name", "i18n.dog.desc"],
+            "getCatLabels" => ["i18n.cat.name", "i18n.cat.desc"],
+            "PetName" => ["i18n.cat.name", "i18n.dog.name"],
+            "CatOrDog" => ["i18n.cat.desc", "i18n.dog.desc"],
+            "Reassigned" => ["i18n.dog.name"],
+            "Branches" => ["i18n.bird", "i18n.cat.name"],
+            "Helper" => ["i18n.cat.desc", "i18n.dog.desc"],
+            "HelperAlias" => ["i18n.cat.name"],
+        );
+    }
+
+    #[test]
+    fn module_side_effects() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                dog: "i18n.dog",
+                saved: "i18n.saved",
+            })
+
+            registerToast(LABELS.saved)
+            if (DEBUG) {
+                console.log(LABELS.bird)
+            }
+            (() => {
+                i18n.init(LABELS.cat)
+            })()
+            const Dog = () => <div>{LABELS.dog}</div>
+            "#,
+            "<module side effects>" => ["i18n.saved", "i18n.bird", "i18n.cat"],
+            "Dog" => ["i18n.dog"],
+        );
+    }
+
+    #[test]
+    fn exported_names() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+            })
+
+            const Foo = () => <div>{LABELS.bird}</div>
+            export const Bar = () => <div>{LABELS.cat}</div>
+            export const { Header, Footer: [Footer] } = createComponents(LABELS.bird)
+            export { Foo as Baz, Foo as "qux" }
+            export default Foo
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "test.js", Options::default())
+            .unwrap()
+            .unwrap();
+        let exported_as = |name: &str| labels_usage.symbols.get(name).unwrap().exported_as.clone();
+        assert_eq!(exported_as("Foo"), ["Baz", "default", "qux"]);
+        assert_eq!(exported_as("Bar"), ["Bar"]);
+        assert_eq!(exported_as("Header"), ["Header"]);
+        assert_eq!(exported_as("Footer"), ["Footer"]);
+        assert!(exported_as("LABELS").is_empty());
+    }
+
+    #[test]
+    fn anonymous_default_export_expression() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+            })
+
+            export default () => <div>{LABELS.bird}</div>
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "src/Bird/index.tsx", Options::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            labels_usage.usage.get("<default>").unwrap(),
+            &HashSet::from(["i18n.bird".to_string()])
+        );
+        let symbol = labels_usage.symbols.get("<default>").unwrap();
+        assert_eq!(symbol.display_name, "Bird");
+        assert_eq!(symbol.kind, SymbolKind::Default);
+        assert_eq!(symbol.exported_as, ["default"]);
+    }
+
+    #[test]
+    fn anonymous_default_export_and_default_property() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+            })
+
+            exports.default = () => <div>{LABELS.cat}</div>
+            module.exports = () => <div>{LABELS.bird}</div>
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "src/Bird.js", Options::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            labels_usage.usage,
+            HashMap::from(
+                [
+                    ("module.exports.default", "i18n.cat"),
+                    ("<default>", "i18n.bird")
+                ]
+                .map(|(symbol, key)| (symbol.to_string(), HashSet::from([key.to_string()])))
+            )
+        );
+        assert_eq!(
+            labels_usage.symbols["module.exports.default"].kind,
+            SymbolKind::Function
+        );
+        assert_eq!(labels_usage.symbols["<default>"].kind, SymbolKind::Default);
+    }
+
+    #[test]
+    fn symbol_kinds() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+            })
+
+            function Foo() {}
+            class Bar {}
+            const Baz = memo(() => <div>{LABELS.bird}</div>)
+            const qux = LABELS.bird
+            registerToast(LABELS.bird)
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "test.js", Options::default())
+            .unwrap()
+            .unwrap();
+        let kind = |name: &str| labels_usage.symbols.get(name).unwrap().kind;
+        assert_eq!(kind("Foo"), SymbolKind::Function);
+        assert_eq!(kind("Bar"), SymbolKind::Class);
+        assert_eq!(kind("Baz"), SymbolKind::Function);
+        assert_eq!(kind("qux"), SymbolKind::Variable);
+        assert_eq!(kind("<module side effects>"), SymbolKind::ModuleSideEffect);
+        // the first statement with side effects, `registerToast(LABELS.bird)`
+        assert_eq!(
+            labels_usage.symbols["<module side effects>"].span,
+            module.body.last().unwrap().span()
+        );
+    }
+
+    #[test]
+    fn typescript_namespaces_and_enums() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                dog: "i18n.dog",
+                fish: "i18n.fish",
+                error: "i18n.error",
+                saved: "i18n.saved",
+            })
+
+            export namespace Admin {
+                const L = LABELS.fish
+                export const Foo = () => <div>{LABELS.bird}</div>
+                export function bar() {
+                    return L
+                }
+                registerToast(LABELS.saved)
+                namespace Users.List {
+                    export class Baz {
+                        render() {
+                            return LABELS.cat
+                        }
+                    }
+                }
+            }
+            declare namespace Ambient {
+                const Foo: typeof LABELS.dog
+            }
+            declare module "some-module" {
+                export const Foo: string
+            }
+            enum Message {
+                Failed = LABELS.error,
+                Plain = "plain",
+            }
+            export = () => <div>{LABELS.dog}</div>
+            "#,
+            "Admin.Foo" => ["i18n.bird"],
+            "Admin.bar" => ["i18n.fish"],
+            "Admin.Users.List.Baz" => ["i18n.cat"],
+            "<module side effects>" => ["i18n.saved"],
+            "Message" => ["i18n.error"],
+            "<default>" => ["i18n.dog"],
+        );
+    }
+
+    #[test]
+    fn qualified_enum_members() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                error: "i18n.error",
+            })
+
+            export enum Message {
+                Failed = LABELS.error,
+            }
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(
+            &module,
+            "test.ts",
+            Options {
+                qualified_symbols: true,
+            },
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            labels_usage.usage,
+            HashMap::from([(
+                "Message.Failed".to_string(),
+                HashSet::from(["i18n.error".to_string()])
+            )])
+        );
+        let symbol = labels_usage.symbols.get("Message").unwrap();
+        assert_eq!(symbol.kind, SymbolKind::Enum);
+        assert_eq!(symbol.exported_as, ["Message"]);
+    }
+
+    #[test]
+    fn destructured_symbols() {
+        assert_label_usage!(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                dog: "i18n.dog",
+                fish: "i18n.fish",
+                error: "i18n.error",
+            })
+
+            export const { Header, Footer } = createComponents(LABELS.bird)
+            const [Cat, Dog = () => LABELS.error, ...Rest] = [
+                () => LABELS.cat,
+                undefined,
+                () => LABELS.dog,
+                () => LABELS.fish,
             ]
+            const { Fish, Other: { Nested } } = {
+                Fish: () => LABELS.fish,
+                Other: { Nested: LABELS.dog },
+                Unused: LABELS.error,
+            }
+            "#,
+            "Header" => ["i18n.bird"],
+            "Footer" => ["i18n.bird"],
+            "Cat" => ["i18n.cat"],
+            "Dog" => ["i18n.error"],
+            "Rest" => ["i18n.dog", "i18n.fish"],
+            "Fish" => ["i18n.fish", "i18n.error"],
+            "Nested" => ["i18n.dog", "i18n.error"],
+        );
+    }
+
+    #[test]
+    fn qualified_symbols() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                dog: "i18n.dog",
+                error: "i18n.error",
+            })
+
+            export class Foo extends Base {
+                static title = LABELS.bird
+                static render() {
+                    return LABELS.cat
+                }
+                handleClick = () => alert(LABELS.dog)
+                #log() {
+                    console.log(LABELS.error)
+                }
+            }
+            export const handlers = {
+                onError() {
+                    toast(LABELS.error)
+                },
+                nested: {
+                    onSave: () => toast(LABELS.bird),
+                },
+                [computed]: LABELS.cat,
+            }
+            "#,
+        )
+        .unwrap();
+        let label_usage = get_labels_usage(
+            &module,
+            "test.js",
+            Options {
+                qualified_symbols: true,
+            },
+        )
+        .unwrap()
+        .unwrap()
+        .usage;
+        let expected = [
+            ("Foo.title", "i18n.bird"),
+            ("Foo.render", "i18n.cat"),
+            ("Foo#handleClick", "i18n.dog"),
+            ("Foo##log", "i18n.error"),
+            ("handlers.onError", "i18n.error"),
+            ("handlers.nested.onSave", "i18n.bird"),
+            ("handlers", "i18n.cat"),
+        ];
+        assert_eq!(label_usage.len(), expected.len());
+        for (symbol, key) in expected {
+            assert_eq!(
+                label_usage.get(symbol).unwrap(),
+                &HashSet::from([key.to_string()]),
+                "{}",
+                symbol
+            );
+        }
+
+        // the members are reported as their owners by default
+        let label_usage = get_labels_usage(&module, "test.js", Options::default())
+            .unwrap()
+            .unwrap()
+            .usage;
+        assert_eq!(label_usage.get("Foo").unwrap().len(), 4);
+        assert_eq!(label_usage.get("handlers").unwrap().len(), 3);
+    }
+
+    fn get_labels_usage_following_props_of(
+        code: &str,
+        summaries: &ProjectSummaries,
+    ) -> LabelsUsage {
+        let module = test_utils::parse_module(code).unwrap();
+        let resolve_import = |source: &str| Some(format!("{}.js", source));
+        let context = ModuleContext {
+            module_path: "./Foo.js",
+            summaries,
+            resolve_import: &resolve_import,
+        };
+        get_labels_usage_following_props(&module, &context, Options::default())
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn follow_props() {
+        let label_usage = get_labels_usage_following_props_of(
+            r#"
+            const LABELS = translate({
+                a: {
+                    b: {
+                        bird: "i18n.bird",
+                        cat: "i18n.cat",
+                    },
+                    dog: "i18n.dog",
+                },
+            })
+
+            const Foo = () => <Bar labels={LABELS.a} />
+            const Bar = ({ labels }) => <Baz baz={labels.b} dog={labels.dog} />
+            function Baz(props) {
+                return <div>{props.baz.bird}</div>
+            }
+            const Qux = () => getCatLabel(LABELS.a.b)
+            const getCatLabel = (birdAndCat) => birdAndCat.cat
+            "#,
+            &ProjectSummaries::new(),
+        )
+        .usage;
+        assert_eq!(
+            label_usage.get("Foo").unwrap(),
+            &HashSet::from(["i18n.bird", "i18n.cat", "i18n.dog"].map(String::from))
+        );
+        assert_eq!(
+            label_usage.get("Bar").unwrap(),
+            &HashSet::from(["i18n.bird", "i18n.cat", "i18n.dog"].map(String::from))
+        );
+        assert_eq!(
+            label_usage.get("Baz").unwrap(),
+            &HashSet::from(["i18n.bird".to_string()])
+        );
+        assert_eq!(
+            label_usage.get("Qux").unwrap(),
+            &HashSet::from(["i18n.bird", "i18n.cat"].map(String::from))
+        );
+        assert_eq!(
+            label_usage.get("getCatLabel").unwrap(),
+            &HashSet::from(["i18n.cat".to_string()])
+        );
+    }
+
+    #[test]
+    fn follow_props_into_imported_modules() {
+        let module = test_utils::parse_module(
+            r#"
+            export default function Bar({ labels }) {
+                return <div>{labels.b.bird}</div>
+            }
+            "#,
+        )
+        .unwrap();
+        let resolve_import = |_: &str| None;
+        let summary = summarize_params(
+            &module,
+            &ModuleContext {
+                module_path: "./Bar.js",
+                summaries: &ProjectSummaries::new(),
+                resolve_import: &resolve_import,
+            },
+        );
+        let summaries = ProjectSummaries::from([("./Bar.js".to_string(), summary)]);
+        let label_usage = get_labels_usage_following_props_of(
+            r#"
+            import Bar from "./Bar"
+            const LABELS = translate({
+                a: {
+                    b: {
+                        bird: "i18n.bird",
+                    },
+                    c: {
+                        cat: "i18n.cat",
+                    },
+                },
+            })
+            const Foo = () => <Bar labels={LABELS.a} />
+            "#,
+            &summaries,
+        );
+        assert_eq!(
+            label_usage.usage.get("Foo").unwrap(),
+            &HashSet::from(["i18n.bird", "i18n.cat"].map(String::from))
+        );
+        assert_eq!(
+            label_usage.external_usage,
+            HashMap::from([(
+                SymbolRef {
+                    module: "./Bar.js".to_string(),
+                    symbol: "Bar".to_string(),
+                },
+                HashSet::from(["i18n.bird".to_string()])
+            )])
+        );
+    }
+
+    #[test]
+    fn commonjs_exports() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+                dog: "i18n.dog",
+                fish: "i18n.fish",
+            })
+
+            const render = () => LABELS.bird
+            exports.subject = () => LABELS.cat
+            module.exports.footer = LABELS.dog
+            module.exports = {
+                render,
+                preview: render,
+                plain() {
+                    return LABELS.fish
+                },
+            }
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "email.js", Options::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            labels_usage.usage,
+            HashMap::from(
+                [
+                    ("render", "i18n.bird"),
+                    ("module.exports.subject", "i18n.cat"),
+                    ("module.exports.footer", "i18n.dog"),
+                    ("module.exports.plain", "i18n.fish"),
+                ]
+                .map(|(symbol, key)| (symbol.to_string(), HashSet::from([key.to_string()])))
+            )
+        );
+        let exported_as = |name: &str| labels_usage.symbols.get(name).unwrap().exported_as.clone();
+        assert_eq!(exported_as("render"), ["preview", "render"]);
+        assert_eq!(exported_as("module.exports.subject"), ["subject"]);
+        assert_eq!(exported_as("module.exports.footer"), ["footer"]);
+        assert_eq!(exported_as("module.exports.plain"), ["plain"]);
+    }
+
+    #[test]
+    fn commonjs_export_next_to_local() {
+        let module = test_utils::parse_module(
+            r#"
+            const LABELS = translate({
+                a: "i18n.a",
+                b: "i18n.b",
+            })
+
+            const render = () => LABELS.b
+            module.exports = {
+                render() {
+                    return LABELS.a
+                },
+            }
+            "#,
+        )
+        .unwrap();
+        let labels_usage = get_labels_usage(&module, "email.js", Options::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            labels_usage.usage,
+            HashMap::from(
+                [("render", "i18n.b"), ("module.exports.render", "i18n.a")]
+                    .map(|(symbol, key)| (symbol.to_string(), HashSet::from([key.to_string()])))
+            )
+        );
+        let exported_as = |name: &str| labels_usage.symbols.get(name).unwrap().exported_as.clone();
+        assert!(exported_as("render").is_empty());
+        assert_eq!(exported_as("module.exports.render"), ["render"]);
+    }
+
+    #[test]
+    fn commonjs_require() {
+        let resolve_import = |source: &str| Some(format!("{}.js", source));
+        let summarize = |module_path: &str, code: &str| {
+            let module = test_utils::parse_module(code).unwrap();
+            let summary = summarize_params(
+                &module,
+                &ModuleContext {
+                    module_path,
+                    summaries: &ProjectSummaries::new(),
+                    resolve_import: &resolve_import,
+                },
+            );
+            (module_path.to_string(), summary)
+        };
+        let summaries = ProjectSummaries::from([
+            summarize(
+                "./labels.js",
+                r#"
+                const LABELS = translate({
+                    a: {
+                        bird: "i18n.bird",
+                        cat: "i18n.cat",
+                    },
+                    dog: "i18n.dog",
+                })
+                module.exports = { LABELS }
+                "#,
+            ),
+            summarize(
+                "./Bar.js",
+                r#"
+                exports.Bar = (props) => props.labels.bird
+                "#,
+            ),
+        ]);
+        let label_usage = get_labels_usage_following_props_of(
+            r#"
+            const { LABELS } = require("./labels")
+            const { Bar } = require("./Bar")
+            const Foo = () => <Bar labels={LABELS.a} />
+            const Baz = () => LABELS.dog
+            "#,
+            &summaries,
+        );
+        assert_eq!(
+            label_usage.usage,
+            HashMap::from([
+                (
+                    "Foo".to_string(),
+                    HashSet::from(["i18n.bird", "i18n.cat"].map(String::from))
+                ),
+                ("Baz".to_string(), HashSet::from(["i18n.dog".to_string()])),
+            ])
+        );
+        assert_eq!(
+            label_usage.external_usage,
+            HashMap::from([(
+                SymbolRef {
+                    module: "./Bar.js".to_string(),
+                    symbol: "module.exports.Bar".to_string(),
+                },
+                HashSet::from(["i18n.bird".to_string()])
+            )])
+        );
+    }
+
+    #[test]
+    fn esm_exported_labels() {
+        let labels = test_utils::parse_module(
+            r#"
+            export const LABELS = translate({
+                bird: "i18n.bird",
+                cat: "i18n.cat",
+            })
+            "#,
         )
+        .unwrap();
+        let summaries = ProjectSummaries::from([(
+            "./labels.js".to_string(),
+            summarize_params(
+                &labels,
+                &ModuleContext {
+                    module_path: "./labels.js",
+                    summaries: &ProjectSummaries::new(),
+                    resolve_import: &|source| Some(format!("{}.js", source)),
+                },
+            ),
+        )]);
+        let label_usage = get_labels_usage_following_props_of(
+            r#"
+            import { LABELS } from "./labels"
+            export const Foo = () => <div>{LABELS.cat}</div>
+            "#,
+            &summaries,
+        );
+        assert_eq!(
+            label_usage.usage,
+            HashMap::from([("Foo".to_string(), HashSet::from(["i18n.cat".to_string()]))])
+        );
     }
 
     #[test]
@@ -1,19 +1,141 @@
 use super::base_case_visitor;
+use super::diagnostic::Diagnostic;
+use super::location::Location;
+use super::symbol::Symbol;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
-use swc_core::ecma::ast::Module;
+use swc_core::{
+    common::{SourceMap, Span},
+    ecma::ast::Module,
+};
+
+pub use super::base_case_visitor::{
+    LabelCall, LazyLabel, ModuleContext, ModuleSummary, Options, ProjectSummaries, SymbolRef,
+    MAX_SUMMARY_ROUNDS,
+};
+
+// The default text written in the code for a key, like the children of
+// `<Trans i18nKey="welcome">Hello <b>{{name}}</b></Trans>`, to be compared
+// with the translations of the Lokalise export.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DefaultText {
+    // `Hello <1>{{name}}</1>`
+    pub text: String,
+    // the interpolation variables, `name`
+    pub variables: Vec<String>,
+    // the component placeholders, `1`
+    pub components: Vec<String>,
+}
+
+impl DefaultText {
+    pub fn new(text: String) -> Self {
+        let mut variables: Vec<String> = vec![];
+        let mut rest = text.as_str();
+        while let Some((_, after)) = rest.split_once("{{") {
+            let (variable, after) = match after.split_once("}}") {
+                Some(split) => split,
+                None => break,
+            };
+            // `{{count, number}}` is formatted
+            let variable = variable.split(',').next().unwrap_or_default().trim();
+            if !variable.is_empty() && !variables.iter().any(|v| v == variable) {
+                variables.push(variable.to_string());
+            }
+            rest = after;
+        }
+
+        let mut components: Vec<String> = vec![];
+        let mut rest = text.as_str();
+        while let Some((_, after)) = rest.split_once('<') {
+            let (tag, after) = match after.split_once('>') {
+                Some(split) => split,
+                None => break,
+            };
+            // `<1>`, `<br/>` and `<bold>`, `</1>` closes one
+            let component = tag.trim_end_matches('/').trim();
+            if !tag.starts_with('/')
+                && !component.is_empty()
+                && !components.iter().any(|c| c == component)
+            {
+                components.push(component.to_string());
+            }
+            rest = after;
+        }
+
+        Self {
+            text,
+            variables,
+            components,
+        }
+    }
+}
+
+// Where a key is called with which interpolation parameters, see `LabelCall`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyCall {
+    pub location: Location,
+    // sorted, `None` if they can't be known, like `LABELS.desc(params)`
+    pub params: Option<Vec<String>>,
+}
 
 #[derive(Debug)]
-struct TranslationUsage {
-    data: HashMap<String, HashSet<String>>,
+pub struct TranslationUsage {
+    // symbol -> translation keys used by the symbol
+    pub data: HashMap<String, HashSet<String>>,
+
+    // symbol -> translation key -> the expressions using the key, see
+    // `get_locations` for their lines and columns
+    pub occurrences: HashMap<String, HashMap<String, Vec<Span>>>,
+
+    // symbol -> translation key -> the calls of the key, like
+    // `LABELS.desc({ date })`, see `get_calls`
+    pub calls: HashMap<String, HashMap<String, Vec<LabelCall>>>,
+
+    // translation keys defined as `['<i18n key>', 'lazy']`, they are functions
+    // to be called with interpolation parameters instead of plain strings
+    pub lazy: HashMap<String, LazyLabel>,
+
+    // translation keys read by the symbols of other modules, which receive a
+    // part of the LABELS as props or arguments, see `ModuleContext`
+    pub external: HashMap<SymbolRef, HashSet<String>>,
+
+    // symbol -> its kind, span and the names it's exported as, `default`
+    // included, so the modules importing them can be connected back to the
+    // keys the symbol uses
+    pub symbols: HashMap<String, Symbol>,
+
+    // symbol -> translation key -> the default texts written where the symbol
+    // uses the key, see `DefaultText`
+    pub default_texts: HashMap<String, HashMap<String, Vec<DefaultText>>>,
+
+    // the problems found in the module, like a lazy label rendered without
+    // being called
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 impl TranslationUsage {
     pub fn new() -> Self {
         Self {
             data: HashMap::new(),
+            occurrences: HashMap::new(),
+            calls: HashMap::new(),
+            lazy: HashMap::new(),
+            external: HashMap::new(),
+            symbols: HashMap::new(),
+            default_texts: HashMap::new(),
+            diagnostics: vec![],
         }
     }
 
+    // the number of symbols using translation keys
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     pub fn extend(&mut self, target: HashMap<String, HashSet<String>>) {
         for (key, value) in target.iter() {
             if !self.data.contains_key(key) {
@@ -24,27 +146,207 @@ impl TranslationUsage {
             });
         }
     }
+
+    // Combine the usage found by another extractor.
+    pub fn merge(&mut self, other: TranslationUsage) {
+        self.extend(other.data);
+        for (symbol, occurrences) in other.occurrences {
+            let symbol_occurrences = self.occurrences.entry(symbol).or_default();
+            for (translation_key, spans) in occurrences {
+                let key_spans = symbol_occurrences.entry(translation_key).or_default();
+                for span in spans {
+                    if !key_spans.contains(&span) {
+                        key_spans.push(span);
+                    }
+                }
+            }
+        }
+        for (symbol, calls) in other.calls {
+            let symbol_calls = self.calls.entry(symbol).or_default();
+            for (translation_key, label_calls) in calls {
+                let key_calls = symbol_calls.entry(translation_key).or_default();
+                for label_call in label_calls {
+                    if !key_calls.contains(&label_call) {
+                        key_calls.push(label_call);
+                    }
+                }
+            }
+        }
+        self.lazy.extend(other.lazy);
+        for (symbol_ref, translation_keys) in other.external {
+            self.external
+                .entry(symbol_ref)
+                .or_default()
+                .extend(translation_keys);
+        }
+        for (name, symbol) in other.symbols {
+            self.symbols.entry(name).or_insert(symbol);
+        }
+        for diagnostic in other.diagnostics {
+            if !self.diagnostics.contains(&diagnostic) {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+        for (symbol, default_texts) in other.default_texts {
+            let symbol_default_texts = self.default_texts.entry(symbol).or_default();
+            for (translation_key, texts) in default_texts {
+                let key_texts = symbol_default_texts.entry(translation_key).or_default();
+                for text in texts {
+                    if !key_texts.contains(&text) {
+                        key_texts.push(text);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn is_lazy(&self, translation_key: &str) -> bool {
+        self.lazy.contains_key(translation_key)
+    }
+
+    // Where `symbol` uses `translation_key`, `source_map` is the one the module
+    // was parsed with.
+    pub fn get_locations(
+        &self,
+        source_map: &SourceMap,
+        file: &str,
+        symbol: &str,
+        translation_key: &str,
+    ) -> Vec<Location> {
+        self.occurrences
+            .get(symbol)
+            .and_then(|occurrences| occurrences.get(translation_key))
+            .map(|spans| {
+                spans
+                    .iter()
+                    .map(|span| Location::new(source_map, file, *span))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // Where `symbol` calls `translation_key` with which parameters.
+    pub fn get_calls(
+        &self,
+        source_map: &SourceMap,
+        file: &str,
+        symbol: &str,
+        translation_key: &str,
+    ) -> Vec<KeyCall> {
+        self.calls
+            .get(symbol)
+            .and_then(|calls| calls.get(translation_key))
+            .map(|label_calls| {
+                label_calls
+                    .iter()
+                    .map(|label_call| KeyCall {
+                        location: Location::new(source_map, file, label_call.span),
+                        params: label_call.params.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_default_texts(&self, symbol: &str, translation_key: &str) -> Vec<DefaultText> {
+        self.default_texts
+            .get(symbol)
+            .and_then(|default_texts| default_texts.get(translation_key))
+            .cloned()
+            .unwrap_or_default()
+    }
 }
 
-pub fn collect_translation(module: &Module) -> anyhow::Result<HashMap<String, HashSet<String>>> {
+impl Default for TranslationUsage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn collect_translation(module: &Module) -> anyhow::Result<TranslationUsage> {
+    collect_translation_with_options(module, "", Options::default())
+}
+
+// `module_path` names the anonymous default export of the module, see
+// `symbol::get_display_name_for_module`.
+pub fn collect_translation_with_options(
+    module: &Module,
+    module_path: &str,
+    options: Options,
+) -> anyhow::Result<TranslationUsage> {
+    let mut translation_usage = TranslationUsage::new();
+    if let Some(v) = base_case_visitor::get_labels_usage(module, module_path, options)? {
+        translation_usage.extend(v.usage);
+        translation_usage.occurrences.extend(v.occurrences);
+        translation_usage.calls.extend(v.calls);
+        translation_usage.diagnostics.extend(v.diagnostics);
+        translation_usage.lazy.extend(v.lazy_labels);
+        translation_usage.symbols.extend(v.symbols);
+    }
+    // Other translation APIs, like `i18nKey` or `translate(<String Literal>)`,
+    // are handled by their own `extractor::Extractor`s.
+
+    Ok(translation_usage)
+}
+
+// Like `collect_translation`, but follow the parts of the LABELS passed as JSX
+// props or call arguments into the receiving functions, which are summarised
+// by `summarize_module` beforehand.
+pub fn collect_translation_following_props(
+    module: &Module,
+    context: &ModuleContext,
+    options: Options,
+) -> anyhow::Result<TranslationUsage> {
     let mut translation_usage = TranslationUsage::new();
-    if let Some(v) = base_case_visitor::get_labels_usage(&module)? {
-        translation_usage.extend(v);
+    if let Some(v) = base_case_visitor::get_labels_usage_following_props(module, context, options)?
+    {
+        translation_usage.extend(v.usage);
+        translation_usage.occurrences.extend(v.occurrences);
+        translation_usage.calls.extend(v.calls);
+        translation_usage.diagnostics.extend(v.diagnostics);
+        translation_usage.lazy.extend(v.lazy_labels);
+        translation_usage.external.extend(v.external_usage);
+        translation_usage.symbols.extend(v.symbols);
     }
-    // Handle more cases here, like:
-    // - LABEL_KEYS
-    // - i18nKey
-    // - translate(<String Literal>)
-    // - ...
 
-    Ok(translation_usage.data)
+    Ok(translation_usage)
+}
+
+// What the top level functions of the module read from their arguments, the
+// summaries of all the modules go into `ModuleContext::summaries`.
+pub fn summarize_module(module: &Module, context: &ModuleContext) -> ModuleSummary {
+    base_case_visitor::summarize_params(module, context)
 }
 
 #[cfg(test)]
+#[allow(clippy::len_zero)]
 mod tests {
     use super::*;
     use crate::test_utils;
 
+    #[test]
+    fn locations() {
+        let (module, source_map) = test_utils::parse_module_with_source_map(
+            r#"const LABELS = translate({
+    bird: "i18n.bird",
+})
+const Foo = () => <div title={LABELS.bird}>{LABELS.bird}</div>"#,
+        )
+        .unwrap();
+        let translation_usage = collect_translation(&module).unwrap();
+        let locations = translation_usage.get_locations(&source_map, "Foo.js", "Foo", "i18n.bird");
+        assert_eq!(
+            locations
+                .iter()
+                .map(|location| (location.line, location.column, location.text.as_str()))
+                .collect::<Vec<_>>(),
+            [(4, 31, "LABELS.bird"), (4, 45, "LABELS.bird")]
+        );
+        assert!(translation_usage
+            .get_locations(&source_map, "Foo.js", "Foo", "i18n.cat")
+            .is_empty());
+    }
+
     #[test]
     fn unsupported_trans_components() {
         let module = test_utils::parse_module(
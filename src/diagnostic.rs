@@ -0,0 +1,118 @@
+use crate::location::Location;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use swc_core::common::{BytePos, SourceMap, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rule {
+    // `{LABELS.desc}` with `desc: ["i18n.desc", "lazy"]`, a lazy label should
+    // be called with its parameters
+    UncalledLazyLabel,
+    // `LABELS.title()` with `title: "i18n.title"`, a plain label is a string
+    CalledEagerLabel,
+    // `<button title="Close">`, a user-facing string which isn't translated
+    HardCodedString,
+    // `translate({ title: "Pet.Title" })`, a key breaking `KeyNamingConfig`
+    KeyNaming,
+    // `translate({ title: "i18n.title", title: "i18n.name" })`, the first
+    // property is overwritten
+    DuplicateLabelProperty,
+    // `translate({ title: "i18n.title", name: "i18n.title" })`
+    DuplicateLabelKey,
+    // `translate({ title: "i18n.title", desc: ["i18n.title", "lazy"] })`
+    ConflictingLabelKey,
+    // `translate({ title: "i18n.title" })` without `LABELS.title` in the module
+    DeadLabelProperty,
+    // `["i18n.desc", "lazy", "plural"]` or `{ plural: "yes" }`, the modifiers
+    // of a lazy label which can't be understood are ignored
+    InvalidLazyModifier,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Rule::UncalledLazyLabel => "uncalled-lazy-label",
+            Rule::CalledEagerLabel => "called-eager-label",
+            Rule::HardCodedString => "hard-coded-string",
+            Rule::KeyNaming => "key-naming",
+            Rule::DuplicateLabelProperty => "duplicate-label-property",
+            Rule::DuplicateLabelKey => "duplicate-label-key",
+            Rule::ConflictingLabelKey => "conflicting-label-key",
+            Rule::DeadLabelProperty => "dead-label-property",
+            Rule::InvalidLazyModifier => "invalid-lazy-modifier",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// A problem found by a visitor, located once the module's source map is known.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub rule: Rule,
+    pub message: String,
+    pub span: Span,
+    // the code removed to fix the problem, see `fix::apply_fixes`
+    pub fix: Option<Span>,
+}
+
+impl Diagnostic {
+    pub fn new(rule: Rule, message: String, span: Span) -> Self {
+        Self {
+            rule,
+            message,
+            span,
+            fix: None,
+        }
+    }
+
+    pub fn with_fix(mut self, fix: Span) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    // `source_map` is the one the module was parsed with.
+    pub fn locate(&self, source_map: &SourceMap, file: &str) -> LocatedDiagnostic {
+        LocatedDiagnostic {
+            rule: self.rule,
+            message: self.message.clone(),
+            location: Location::new(source_map, file, self.span),
+            fix: self.fix.map(|fix| Fix {
+                start: get_offset(source_map, fix.lo),
+                end: get_offset(source_map, fix.hi),
+            }),
+        }
+    }
+}
+
+// The offset of the position in the text of its file.
+fn get_offset(source_map: &SourceMap, pos: BytePos) -> usize {
+    source_map.lookup_byte_offset(pos).pos.0 as usize
+}
+
+// Remove the bytes `start..end` of the module's text, before
+// `fix::apply_fixes` tidies the commas and the blank lines around them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Fix {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LocatedDiagnostic {
+    pub rule: Rule,
+    pub message: String,
+    pub location: Location,
+    pub fix: Option<Fix>,
+}
+
+// `src/Foo.tsx:3:14: "i18n.desc" is a lazy label ... (uncalled-lazy-label)`
+impl fmt::Display for LocatedDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} ({})",
+            self.location.file, self.location.line, self.location.column, self.message, self.rule
+        )
+    }
+}
@@ -0,0 +1,102 @@
+use crate::analyzer::AnalyzerConfig;
+use crate::core::{self, ModuleContext, Options, TranslationUsage};
+use crate::i18next_visitor;
+use crate::react_intl_visitor;
+use swc_core::{
+    common::{comments::SingleThreadedComments, SourceMap},
+    ecma::ast::Module,
+};
+
+// What an extractor knows about the module besides its AST.
+pub struct ExtractorContext<'a> {
+    // the path of the module, the summaries of the project and how the imports
+    // of the module are resolved
+    pub module_context: &'a ModuleContext<'a>,
+    pub config: AnalyzerConfig,
+    // the source map and the comments the module was parsed with
+    pub source_map: &'a SourceMap,
+    pub comments: &'a SingleThreadedComments,
+}
+
+impl ExtractorContext<'_> {
+    pub fn get_options(&self) -> Options {
+        Options {
+            qualified_symbols: self.config.qualified_symbols,
+        }
+    }
+}
+
+// Find the translation keys used by the symbols of a module, for a translation
+// API like `LABELS = translate({ /* ... */ })`. The module is already resolved,
+// so the bindings with the same name in different scopes are told apart.
+//
+// The usages of all the registered extractors are merged, see
+// `Analyzer::add_extractor`.
+pub trait Extractor {
+    fn extract(
+        &self,
+        module: &Module,
+        context: &ExtractorContext,
+    ) -> anyhow::Result<TranslationUsage>;
+}
+
+// `const LABELS = translate({ /* ... */ })`, registered by default.
+pub struct LabelsExtractor;
+
+impl Extractor for LabelsExtractor {
+    fn extract(
+        &self,
+        module: &Module,
+        context: &ExtractorContext,
+    ) -> anyhow::Result<TranslationUsage> {
+        let options = context.get_options();
+        match context.config.follow_props {
+            true => {
+                core::collect_translation_following_props(module, context.module_context, options)
+            }
+            false => core::collect_translation_with_options(
+                module,
+                context.module_context.module_path,
+                options,
+            ),
+        }
+    }
+}
+
+// react-i18next and i18next, `const { t } = useTranslation("ns"); t("key")`,
+// `withTranslation("ns")(Foo)` and `i18n.t("key")`, see
+// `AnalyzerConfig::i18next`.
+pub struct I18nextExtractor;
+
+impl Extractor for I18nextExtractor {
+    fn extract(
+        &self,
+        module: &Module,
+        context: &ExtractorContext,
+    ) -> anyhow::Result<TranslationUsage> {
+        Ok(i18next_visitor::get_i18next_usage(
+            module,
+            context.module_context.module_path,
+            context.get_options(),
+        ))
+    }
+}
+
+// react-intl, `const messages = defineMessages({ /* ... */ })` read as
+// `intl.formatMessage(messages.greeting)`, `<FormattedMessage id="..." />` and
+// `intl.formatMessage({ id: "..." })`, see `AnalyzerConfig::react_intl`.
+pub struct ReactIntlExtractor;
+
+impl Extractor for ReactIntlExtractor {
+    fn extract(
+        &self,
+        module: &Module,
+        context: &ExtractorContext,
+    ) -> anyhow::Result<TranslationUsage> {
+        Ok(react_intl_visitor::get_react_intl_usage(
+            module,
+            context.module_context.module_path,
+            context.get_options(),
+        ))
+    }
+}
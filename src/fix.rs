@@ -0,0 +1,212 @@
+use crate::diagnostic::{Fix, LocatedDiagnostic};
+use anyhow::Context;
+use std::path::Path;
+
+// The start of the line `offset` is on.
+fn get_line_start(text: &str, offset: usize) -> usize {
+    text[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+// `// shown under the title` or `/* shown under the title */`
+fn is_comment(text: &str) -> bool {
+    let text = text.trim();
+    text.starts_with("//") || (text.starts_with("/*") && text.find("*/") == Some(text.len() - 2))
+}
+
+// Widen the removed range so the rest of the object literal is untouched: the
+// comma after the property goes with it, and so does its line if nothing else
+// is written on it but a trailing comment. Without a comma after it, the
+// property is the last one and the comma before it is removed instead.
+// Comments on other lines are kept.
+fn widen(text: &str, fix: Fix) -> Fix {
+    let Fix { start, mut end } = fix;
+    let after = &text[end..];
+    let trimmed = after.trim_start_matches([' ', '\t']);
+    let has_comma = trimmed.starts_with(',');
+    if has_comma {
+        end += after.len() - trimmed.len() + 1;
+    }
+    let line_start = get_line_start(text, start);
+    let rest = &text[end..];
+    let line_end = rest.find('\n').map(|i| end + i + 1).unwrap_or(text.len());
+    let line_rest = &text[end..line_end];
+    if text[line_start..start].trim().is_empty()
+        && (line_rest.trim().is_empty() || is_comment(line_rest))
+    {
+        return Fix {
+            start: line_start,
+            end: line_end,
+        };
+    }
+    if has_comma {
+        // `{ a: "i18n.a", b: "i18n.b" }` -> `{ b: "i18n.b" }`
+        let rest = &text[end..];
+        return Fix {
+            start,
+            end: end + rest.len() - rest.trim_start_matches([' ', '\t']).len(),
+        };
+    }
+    let before = text[..start].trim_end();
+    match before.ends_with(',') {
+        // `{ a: "i18n.a", b: "i18n.b" }` -> `{ a: "i18n.a" }`
+        true => Fix {
+            start: before.len() - 1,
+            end,
+        },
+        false => Fix { start, end },
+    }
+}
+
+// `{ a: "i18n.a" }` -> `{}` once all the properties are removed, the padding
+// left between the braces goes too.
+fn collapse_empty_object(text: &str, fix: Fix) -> Fix {
+    let before = text[..fix.start].trim_end();
+    let after = text[fix.end..].trim_start();
+    match before.ends_with('{') && after.starts_with('}') {
+        true => Fix {
+            start: before.len(),
+            end: text.len() - after.len(),
+        },
+        false => fix,
+    }
+}
+
+// Apply the fixes of the diagnostics to the text of their module, the fixes
+// removing the same code, overlapping or adjacent ones are merged.
+pub fn apply_fixes(text: &str, diagnostics: &[LocatedDiagnostic]) -> String {
+    let mut fixes: Vec<Fix> = diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic.fix)
+        .map(|fix| widen(text, fix))
+        .collect();
+    fixes.sort_by_key(|fix| (fix.start, std::cmp::Reverse(fix.end)));
+    let mut merged: Vec<Fix> = vec![];
+    for fix in fixes {
+        match merged.last_mut() {
+            Some(last) if fix.start <= last.end => last.end = last.end.max(fix.end),
+            _ => merged.push(fix),
+        }
+    }
+    let mut fixed = String::with_capacity(text.len());
+    let mut copied_to = 0;
+    for fix in merged {
+        let fix = collapse_empty_object(text, fix);
+        fixed.push_str(&text[copied_to..fix.start]);
+        copied_to = fix.end;
+    }
+    fixed.push_str(&text[copied_to..]);
+    fixed
+}
+
+// Rewrite the file with the fixes of its diagnostics, returns how many
+// diagnostics are fixed.
+pub fn fix_file(path: &Path, diagnostics: &[LocatedDiagnostic]) -> anyhow::Result<usize> {
+    let fixed_count = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.fix.is_some())
+        .count();
+    if fixed_count == 0 {
+        return Ok(0);
+    }
+    let text = std::fs::read_to_string(path).context(format!("failed to load {:?}", path))?;
+    std::fs::write(path, apply_fixes(&text, diagnostics))
+        .context(format!("failed to write {:?}", path))?;
+    Ok(fixed_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{Analyzer, AnalyzerConfig};
+
+    fn fix(text: &str) -> String {
+        let analyzer = Analyzer::new(AnalyzerConfig::default());
+        let module_analysis = analyzer.analyze_source("src/Foo.tsx", text).unwrap();
+        apply_fixes(text, &module_analysis.diagnostics)
+    }
+
+    #[test]
+    fn dead_properties() {
+        assert_eq!(
+            fix(r#"const LABELS = translate({
+    // the title of the page
+    title: "i18n.title",
+    desc: ["i18n.desc", "lazy"], // shown under the title
+    walk: {
+        cat: "i18n.walk.cat",
+        dog: "i18n.walk.dog",
+    },
+    pets: { cat: "i18n.cat", dog: "i18n.dog", bird: "i18n.bird" },
+    name: "i18n.name"
+});
+export const Foo = () => <div>{LABELS.title}{LABELS.pets.dog}</div>;
+"#),
+            r#"const LABELS = translate({
+    // the title of the page
+    title: "i18n.title",
+    pets: { dog: "i18n.dog" },
+});
+export const Foo = () => <div>{LABELS.title}{LABELS.pets.dog}</div>;
+"#
+        );
+    }
+
+    #[test]
+    fn computed_subtree() {
+        assert_eq!(
+            fix(r#"const LABELS = translate({
+    used: "i18n.used",
+    size: {
+        [SIZE.S]: "i18n.size.s",
+        [SIZE.L]: "i18n.size.l",
+    },
+});
+export const Foo = () => <div>{LABELS.used}</div>;
+"#),
+            r#"const LABELS = translate({
+    used: "i18n.used",
+});
+export const Foo = () => <div>{LABELS.used}</div>;
+"#
+        );
+    }
+
+    #[test]
+    fn single_line() {
+        assert_eq!(
+            fix(
+                r#"const LABELS = translate({ title: "i18n.title", name: "i18n.name" });
+export const Foo = () => <div />;
+"#
+            ),
+            r#"const LABELS = translate({});
+export const Foo = () => <div />;
+"#
+        );
+    }
+
+    #[test]
+    fn empty_object() {
+        assert_eq!(
+            fix(r#"const LABELS = translate({
+    title: "i18n.title", /* the title */
+    pets: {
+        cat: "i18n.cat",
+    },
+});
+export const Foo = () => <div />;
+"#),
+            r#"const LABELS = translate({});
+export const Foo = () => <div />;
+"#
+        );
+    }
+
+    #[test]
+    fn nothing_to_fix() {
+        let text = r#"const LABELS = translate({ title: "i18n.title" });
+export const Foo = () => <div title="Close">{LABELS.title}</div>;
+"#;
+        assert_eq!(fix(text), text);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::core::TranslationUsage;
+use crate::diagnostic::{Diagnostic, Rule};
+use crate::extractor::{Extractor, ExtractorContext};
+use crate::i18next_visitor::get_jsx_text;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use swc_core::{
+    common::{comments::SingleThreadedComments, BytePos, SourceMap, SourceMapper, Span, Spanned},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+// `// i18n-ignore` or `{/* i18n-ignore */}` allows the strings on its line, and
+// on the next one when it's alone on its line.
+const IGNORE_COMMENT: &str = "i18n-ignore";
+
+// The elements whose children are translated or aren't user-facing.
+const SKIPPED_ELEMENTS: [&str; 6] = [
+    "Trans",
+    "TransBlock",
+    "FormattedMessage",
+    "FormattedHTMLMessage",
+    "script",
+    "style",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardCodedStringsConfig {
+    // the attributes showing their string values to the users
+    pub attributes: Vec<String>,
+    // the strings which don't need to be translated, like brand names
+    pub allowlist: Vec<String>,
+}
+
+impl Default for HardCodedStringsConfig {
+    fn default() -> Self {
+        Self {
+            attributes: ["title", "placeholder", "aria-label", "alt"]
+                .map(|attribute| attribute.to_string())
+                .to_vec(),
+            allowlist: vec![],
+        }
+    }
+}
+
+struct HardCodedStringsVisitor<'a> {
+    config: &'a HardCodedStringsConfig,
+
+    // the lines allowed by the ignore comments
+    ignored_lines: HashSet<usize>,
+
+    source_map: &'a SourceMap,
+
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> HardCodedStringsVisitor<'a> {
+    fn new(
+        config: &'a HardCodedStringsConfig,
+        source_map: &'a SourceMap,
+        comments: &SingleThreadedComments,
+    ) -> Self {
+        let mut ignored_lines = HashSet::new();
+        let (leading, trailing) = comments.borrow_all();
+        for comment in leading.values().chain(trailing.values()).flatten() {
+            if comment.text.contains(IGNORE_COMMENT) {
+                let loc = source_map.lookup_char_pos(comment.span.lo);
+                ignored_lines.insert(loc.line);
+                // `<img alt="Logo" /> {/* i18n-ignore */}` only allows its own line
+                let is_alone = match (
+                    loc.file.get_line(loc.line - 1),
+                    source_map.span_to_snippet(comment.span),
+                ) {
+                    (Some(line), Ok(snippet)) => {
+                        matches!(line.replacen(&snippet, "", 1).trim(), "" | "{}")
+                    }
+                    _ => false,
+                };
+                if is_alone {
+                    ignored_lines.insert(loc.line + 1);
+                }
+            }
+        }
+        Self {
+            config,
+            ignored_lines,
+            source_map,
+            diagnostics: vec![],
+        }
+    }
+
+    fn check(&mut self, text: &str, span: Span) {
+        let text = text.trim();
+        // numbers, punctuation and symbols like `×` read the same in every language
+        if !text.chars().any(|c| c.is_alphabetic()) {
+            return;
+        }
+        if self.config.allowlist.iter().any(|allowed| allowed == text) {
+            return;
+        }
+        let line = self.source_map.lookup_char_pos(span.lo).line;
+        if self.ignored_lines.contains(&line) {
+            return;
+        }
+        self.diagnostics.push(Diagnostic::new(
+            Rule::HardCodedString,
+            format!("\"{}\" is hard-coded, translate it with a label", text),
+            span,
+        ));
+    }
+}
+
+impl Visit for HardCodedStringsVisitor<'_> {
+    fn visit_jsx_element(&mut self, node: &JSXElement) {
+        match &node.opening.name {
+            JSXElementName::Ident(ident) if SKIPPED_ELEMENTS.contains(&&*ident.sym) => {
+                node.opening.visit_with(self)
+            }
+            _ => node.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_text(&mut self, node: &JSXText) {
+        // point at the text, not at the whitespace around it
+        let leading = node.raw.len() - node.raw.trim_start().len();
+        let span = Span::new(
+            node.span.lo + BytePos(leading as u32),
+            node.span.lo + BytePos(node.raw.trim_end().len() as u32),
+        );
+        self.check(&get_jsx_text(&node.value), span);
+    }
+
+    // `title="Close"`, `title={"Close"}` or ``title={`Close`}``
+    fn visit_jsx_attr(&mut self, node: &JSXAttr) {
+        let name = match &node.name {
+            JSXAttrName::Ident(ident) => ident.sym.to_string(),
+            JSXAttrName::JSXNamespacedName(_) => return node.visit_children_with(self),
+        };
+        if self.config.attributes.contains(&name) {
+            match &node.value {
+                Some(JSXAttrValue::Lit(Lit::Str(str))) => self.check(&str.value, str.span),
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })) => match &**expr {
+                    Expr::Lit(Lit::Str(str)) => self.check(&str.value, str.span),
+                    Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => {
+                        if let Some(quasi) = quasis.first() {
+                            self.check(&quasi.raw, expr.span());
+                        }
+                    }
+                    _ => (),
+                },
+                _ => (),
+            }
+        }
+        node.visit_children_with(self);
+    }
+}
+
+// Flag the user-facing strings written in JSX instead of going through the
+// labels or `<Trans>`, the text of the elements and the string values of
+// `HardCodedStringsConfig::attributes`. It only reports diagnostics, register
+// it with `Analyzer::add_extractor`.
+pub struct HardCodedStringsLint {
+    config: HardCodedStringsConfig,
+}
+
+impl HardCodedStringsLint {
+    pub fn new(config: HardCodedStringsConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Extractor for HardCodedStringsLint {
+    fn extract(
+        &self,
+        module: &Module,
+        context: &ExtractorContext,
+    ) -> anyhow::Result<TranslationUsage> {
+        let mut visitor =
+            HardCodedStringsVisitor::new(&self.config, context.source_map, context.comments);
+        module.visit_with(&mut visitor);
+        let mut translation_usage = TranslationUsage::new();
+        translation_usage.diagnostics = visitor.diagnostics;
+        Ok(translation_usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{Analyzer, AnalyzerConfig};
+
+    #[test]
+    fn hard_coded_strings() {
+        let mut analyzer = Analyzer::new(AnalyzerConfig::default());
+        analyzer.add_extractor(HardCodedStringsLint::new(HardCodedStringsConfig {
+            allowlist: vec!["Lokalise".to_string()],
+            ..Default::default()
+        }));
+        let module_analysis = analyzer
+            .analyze_source(
+                "src/Foo.tsx",
+                r#"const LABELS = translate({ title: "i18n.title" });
+export const Foo = () => (
+    <div title="Close" aria-label={`Menu`} className="header" alt={LABELS.title}>
+        Hello <b>{LABELS.title}</b> ×
+        <Lokalise>Lokalise</Lokalise> 42
+        <Trans i18nKey="i18n.welcome">Welcome</Trans>
+        {/* i18n-ignore */}
+        <input placeholder="Search" />
+        <img alt="Logo" /> {/* i18n-ignore */}
+        <button title="Save" />
+    </div>
+);
+// i18n-ignore
+export const Bar = () => <i title="Bar" />;
+"#,
+            )
+            .unwrap();
+        assert_eq!(
+            module_analysis
+                .diagnostics
+                .iter()
+                .map(|diagnostic| (
+                    diagnostic.rule,
+                    diagnostic.location.line,
+                    diagnostic.location.text.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (Rule::HardCodedString, 3, r#""Close""#),
+                (Rule::HardCodedString, 3, "`Menu`"),
+                (Rule::HardCodedString, 4, "Hello"),
+                (Rule::HardCodedString, 10, r#""Save""#),
+            ]
+        );
+    }
+}
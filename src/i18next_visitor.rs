@@ -0,0 +1,717 @@
+use crate::base_case_visitor::{get_prop_name, SymbolScope, SymbolVisitor};
+use crate::core::{DefaultText, Options, TranslationUsage};
+use std::collections::HashMap;
+use swc_core::{
+    common::Span,
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+// `i18next.t("key")` and `i18n.t("key")` use the default namespace.
+const I18N_INSTANCES: [&str; 2] = ["i18n", "i18next"];
+
+// The html elements `<Trans>` keeps as they are in the default text when they
+// have no attributes, react-i18next's `transKeepBasicHtmlNodesFor`.
+const TRANS_KEPT_HTML_ELEMENTS: [&str; 4] = ["br", "strong", "i", "p"];
+
+// What a `t` function prepends to the keys, like the `t` of
+// `useTranslation("ns", { keyPrefix: "a.b" })` turns `t("c")` into `ns:a.b.c`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TFunction {
+    namespace: Option<String>,
+    key_prefix: Option<String>,
+}
+
+impl TFunction {
+    // `useTranslation("ns")`, `useTranslation(["ns", "common"])` and
+    // `useTranslation("ns", { keyPrefix: "a.b" })`, the first namespace is the
+    // default one.
+    fn from_args(namespace: Option<&ExprOrSpread>, options: Option<&ExprOrSpread>) -> Self {
+        Self {
+            namespace: namespace.and_then(|namespace| get_namespace(&namespace.expr)),
+            key_prefix: options.and_then(|options| get_string_option(&options.expr, "keyPrefix")),
+        }
+    }
+
+    // `t("key")`, `t("ns:key")`, `t(["key", "fallback"])` or
+    // `t("key", { ns: "ns" })`
+    fn get_translation_keys(&self, args: &[ExprOrSpread]) -> Vec<String> {
+        let keys = match args.first() {
+            Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+                Expr::Array(ArrayLit { elems, .. }) => elems
+                    .iter()
+                    .flatten()
+                    .filter_map(|elem| get_string(&elem.expr))
+                    .collect(),
+                expr => get_string(expr).into_iter().collect(),
+            },
+            _ => vec![],
+        };
+        let namespace = args
+            .get(1)
+            .and_then(|options| get_string_option(&options.expr, "ns"));
+        keys.into_iter()
+            .map(|key| self.get_translation_key(key, namespace.clone()))
+            .collect()
+    }
+
+    // `key` -> `ns:prefix.key`, the namespace of `ns:key` or `namespace`
+    // overrides the one of the `t` function
+    fn get_translation_key(&self, key: String, namespace: Option<String>) -> String {
+        let (namespace, key) = match key.split_once(':') {
+            Some((namespace, key)) => (Some(namespace.to_string()), key.to_string()),
+            None => (namespace.or_else(|| self.namespace.clone()), key),
+        };
+        let key = match &self.key_prefix {
+            Some(key_prefix) => format!("{}.{}", key_prefix, key),
+            None => key,
+        };
+        match namespace {
+            Some(namespace) => format!("{}:{}", namespace, key),
+            None => key,
+        }
+    }
+}
+
+fn get_string(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => {
+            Some(quasis.first()?.raw.to_string())
+        }
+        _ => None,
+    }
+}
+
+// `"ns"` or `["ns", "common"]`
+fn get_namespace(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Array(ArrayLit { elems, .. }) => get_string(&elems.first()?.as_ref()?.expr),
+        expr => get_string(expr),
+    }
+}
+
+// `{ keyPrefix: "a.b" }` -> "a.b"
+fn get_string_option(expr: &Expr, name: &str) -> Option<String> {
+    match expr {
+        Expr::Object(ObjectLit { props, .. }) => props.iter().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(KeyValueProp { key, value }) => match get_prop_name(key)? == name {
+                    true => match name {
+                        "ns" => get_namespace(value),
+                        _ => get_string(value),
+                    },
+                    false => None,
+                },
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        }),
+        _ => None,
+    }
+}
+
+fn is_i18n_instance(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(ident) => I18N_INSTANCES.contains(&&*ident.sym),
+        _ => false,
+    }
+}
+
+// `useTranslation("ns")` returns `{ t }` or `[t]`
+fn get_use_translation(expr: &Expr) -> Option<TFunction> {
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => match &**callee {
+            Expr::Ident(ident) if ident.sym == "useTranslation" => {
+                Some(TFunction::from_args(args.first(), args.get(1)))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// `i18n.getFixedT(lng, "ns", "a.b")` returns a `t` function, `i18n.t` is one.
+fn get_t_function_of(expr: &Expr) -> Option<TFunction> {
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if is_i18n_instance(obj) && prop.sym == "getFixedT" => Some(TFunction {
+                namespace: args.get(1).and_then(|arg| get_namespace(&arg.expr)),
+                key_prefix: args.get(2).and_then(|arg| get_string(&arg.expr)),
+            }),
+            _ => None,
+        },
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if is_i18n_instance(obj) && prop.sym == "t" => Some(TFunction::default()),
+        Expr::Await(AwaitExpr { arg, .. }) => get_t_function_of(arg),
+        _ => None,
+    }
+}
+
+// `<Trans i18nKey="key" />` -> the value of `i18nKey`
+fn get_jsx_attr<'a>(opening: &'a JSXOpeningElement, name: &str) -> Option<&'a JSXAttrValue> {
+    opening.attrs.iter().find_map(|attr| match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(attr_name),
+            value: Some(value),
+            ..
+        }) if attr_name.sym == name => Some(value),
+        _ => None,
+    })
+}
+
+// `"key"` or `{"key"}`
+fn get_jsx_attr_string(value: &JSXAttrValue) -> Option<String> {
+    match value {
+        JSXAttrValue::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        }) => get_string(expr),
+        _ => None,
+    }
+}
+
+// JSX drops the lines of whitespace and joins the others with a space.
+pub(crate) fn get_jsx_text(value: &str) -> String {
+    let lines: Vec<&str> = value.split('\n').collect();
+    let last = lines.len() - 1;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = match i == 0 {
+                true => line,
+                false => line.trim_start(),
+            };
+            match i == last {
+                true => line,
+                false => line.trim_end(),
+            }
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// The children of `<Trans>` as react-i18next serializes them into the default
+// text, the elements become their indexes, `Hello <b>{{name}}</b>` is
+// `Hello <1>{{name}}</1>`.
+fn get_trans_text(children: &[JSXElementChild]) -> String {
+    let mut text = String::new();
+    let mut index = 0;
+    for child in children {
+        match child {
+            JSXElementChild::JSXText(jsx_text) => {
+                let jsx_text = get_jsx_text(&jsx_text.value);
+                if jsx_text.is_empty() {
+                    continue;
+                }
+                text.push_str(&jsx_text);
+            }
+            JSXElementChild::JSXExprContainer(JSXExprContainer { expr, .. }) => match expr {
+                // `{{ name }}` or `{{ count, format: "number" }}`
+                JSXExpr::Expr(expr) => match &**expr {
+                    Expr::Object(ObjectLit { props, .. }) => {
+                        let variable = props.iter().find_map(|prop| match prop {
+                            PropOrSpread::Prop(prop) => match &**prop {
+                                Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+                                Prop::KeyValue(KeyValueProp { key, .. }) => {
+                                    get_prop_name(key).filter(|name| name != "format")
+                                }
+                                _ => None,
+                            },
+                            PropOrSpread::Spread(_) => None,
+                        });
+                        if let Some(variable) = variable {
+                            text.push_str(&format!("{{{{{}}}}}", variable));
+                        }
+                    }
+                    // `{" "}`
+                    expr => {
+                        if let Some(s) = get_string(expr) {
+                            text.push_str(&s);
+                        }
+                    }
+                },
+                // `{/* comment */}` isn't a child
+                JSXExpr::JSXEmptyExpr(_) => continue,
+            },
+            JSXElementChild::JSXElement(element) => {
+                // `<strong>bold</strong>` or `<br />`
+                let kept_name = match &element.opening.name {
+                    JSXElementName::Ident(ident)
+                        if TRANS_KEPT_HTML_ELEMENTS.contains(&&*ident.sym)
+                            && element.opening.attrs.is_empty()
+                            && element
+                                .children
+                                .iter()
+                                .all(|child| matches!(child, JSXElementChild::JSXText(_))) =>
+                    {
+                        Some(ident.sym.to_string())
+                    }
+                    _ => None,
+                };
+                let inner = get_trans_text(&element.children);
+                match (kept_name, &element.closing) {
+                    (Some(name), Some(_)) => {
+                        text.push_str(&format!("<{}>{}</{}>", name, inner, name))
+                    }
+                    (Some(name), None) => text.push_str(&format!("<{}/>", name)),
+                    (None, _) => text.push_str(&format!("<{}>{}</{}>", index, inner, index)),
+                }
+            }
+            JSXElementChild::JSXFragment(fragment) => {
+                let inner = get_trans_text(&fragment.children);
+                text.push_str(&format!("<{}>{}</{}>", index, inner, index));
+            }
+            JSXElementChild::JSXSpreadChild(_) => (),
+        }
+        index += 1;
+    }
+    text
+}
+
+// `withTranslation("ns")(Foo)` -> ("Foo", the `t` injected into the props)
+fn get_wrapped_component(call_expr: &CallExpr) -> Option<(String, TFunction)> {
+    let hoc = match &call_expr.callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Call(hoc) => hoc,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    match &hoc.callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Ident(ident) if ident.sym == "withTranslation" => (),
+            _ => return None,
+        },
+        _ => return None,
+    }
+    match call_expr.args.first() {
+        Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+            Expr::Ident(component) => Some((
+                component.sym.to_string(),
+                TFunction::from_args(hoc.args.first(), hoc.args.get(1)),
+            )),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct WrappedComponentVisitor {
+    wrapped_components: HashMap<String, TFunction>,
+}
+
+impl Visit for WrappedComponentVisitor {
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Some((component, t_function)) = get_wrapped_component(node) {
+            self.wrapped_components.insert(component, t_function);
+        }
+        node.visit_children_with(self);
+    }
+}
+
+struct I18nextVisitor {
+    // the top level symbol being visited
+    scope: SymbolScope,
+
+    // the `t` functions declared so far
+    t_functions: HashMap<Id, TFunction>,
+
+    // component -> the `t` injected into its props by `withTranslation`
+    wrapped_components: HashMap<String, TFunction>,
+
+    usage: TranslationUsage,
+}
+
+impl I18nextVisitor {
+    fn new(
+        module_path: &str,
+        wrapped_components: HashMap<String, TFunction>,
+        options: Options,
+    ) -> Self {
+        Self {
+            scope: SymbolScope::new(module_path, options),
+            t_functions: HashMap::new(),
+            wrapped_components,
+            usage: TranslationUsage::new(),
+        }
+    }
+
+    // The `t` injected by `withTranslation`, read as `t`, `props.t` or
+    // `this.props.t` in the wrapped component.
+    fn get_injected_t_function(&self, callee: &Expr) -> Option<TFunction> {
+        // the component itself, not one of its members
+        let component = self.scope.current_id.as_ref()?.get_name();
+        let t_function = self.wrapped_components.get(&component)?;
+        let is_injected = match callee {
+            Expr::Ident(ident) => ident.sym == "t",
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                prop.sym == "t"
+                    && match &**obj {
+                        Expr::Ident(ident) => ident.sym == "props",
+                        Expr::Member(MemberExpr {
+                            obj,
+                            prop: MemberProp::Ident(prop),
+                            ..
+                        }) => prop.sym == "props" && matches!(&**obj, Expr::This(_)),
+                        _ => false,
+                    }
+            }
+            _ => false,
+        };
+        match is_injected {
+            true => Some(t_function.clone()),
+            false => None,
+        }
+    }
+
+    fn get_callee_t_function(&self, callee: &Expr) -> Option<TFunction> {
+        match callee {
+            Expr::Ident(ident) => match self.t_functions.get(&ident.to_id()) {
+                Some(t_function) => Some(t_function.clone()),
+                None => self.get_injected_t_function(callee),
+            },
+            // i18n.t("key")
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if is_i18n_instance(obj) && prop.sym == "t" => Some(TFunction::default()),
+            callee => self.get_injected_t_function(callee),
+        }
+    }
+
+    // `<Trans i18nKey="key" ns="ns" t={t} defaults="...">children</Trans>`, the
+    // children or `defaults` are the default text, which is the key too
+    // without `i18nKey`
+    fn visit_trans(&mut self, node: &JSXElement) {
+        let t_function = match get_jsx_attr(&node.opening, "t") {
+            Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            })) => self.get_callee_t_function(expr).unwrap_or_default(),
+            _ => TFunction::default(),
+        };
+        let namespace = get_jsx_attr(&node.opening, "ns").and_then(get_jsx_attr_string);
+        let text = match get_jsx_attr(&node.opening, "defaults").and_then(get_jsx_attr_string) {
+            Some(defaults) => defaults,
+            None => get_trans_text(&node.children),
+        };
+        let key = match get_jsx_attr(&node.opening, "i18nKey").and_then(get_jsx_attr_string) {
+            Some(key) => key,
+            None if !text.is_empty() => text.clone(),
+            None => return,
+        };
+        let translation_key = t_function.get_translation_key(key, namespace);
+        self.record_usage(vec![translation_key.clone()], node.opening.span);
+        if text.is_empty() {
+            return;
+        }
+        if let Some(symbol) = self.scope.get_current_symbol() {
+            let default_texts = self
+                .usage
+                .default_texts
+                .entry(symbol)
+                .or_default()
+                .entry(translation_key)
+                .or_default();
+            let default_text = DefaultText::new(text);
+            if !default_texts.contains(&default_text) {
+                default_texts.push(default_text);
+            }
+        }
+    }
+
+    // `const { t } = useTranslation("ns")`, `const [t] = useTranslation()` or
+    // `const t = i18n.getFixedT(null, "ns")`
+    fn declare_t_function(&mut self, node: &VarDeclarator) {
+        let init = node.init.as_deref();
+        let t = match &node.name {
+            Pat::Ident(BindingIdent { id, .. }) => {
+                init.and_then(get_t_function_of).map(|t| (id, t))
+            }
+            Pat::Object(ObjectPat { props, .. }) => {
+                init.and_then(get_use_translation).and_then(|t| {
+                    props.iter().find_map(|prop| match prop {
+                        ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                            match (get_prop_name(key)?.as_str(), &**value) {
+                                ("t", Pat::Ident(BindingIdent { id, .. })) => Some((id, t.clone())),
+                                _ => None,
+                            }
+                        }
+                        ObjectPatProp::Assign(AssignPatProp { key, .. }) => match key.sym == "t" {
+                            true => Some((&key.id, t.clone())),
+                            false => None,
+                        },
+                        ObjectPatProp::Rest(_) => None,
+                    })
+                })
+            }
+            Pat::Array(ArrayPat { elems, .. }) => match elems.first() {
+                Some(Some(Pat::Ident(BindingIdent { id, .. }))) => {
+                    init.and_then(get_use_translation).map(|t| (id, t))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some((id, t_function)) = t {
+            self.t_functions.insert(id.to_id(), t_function);
+        }
+    }
+
+    fn record_usage(&mut self, translation_keys: Vec<String>, span: Span) {
+        let symbol = match self.scope.get_current_symbol() {
+            Some(symbol) => symbol,
+            None => return,
+        };
+        for translation_key in translation_keys {
+            self.usage
+                .data
+                .entry(symbol.clone())
+                .or_default()
+                .insert(translation_key.clone());
+            let spans = self
+                .usage
+                .occurrences
+                .entry(symbol.clone())
+                .or_default()
+                .entry(translation_key)
+                .or_default();
+            if !spans.contains(&span) {
+                spans.push(span);
+            }
+        }
+    }
+}
+
+impl SymbolVisitor for I18nextVisitor {
+    fn symbol_scope(&mut self) -> &mut SymbolScope {
+        &mut self.scope
+    }
+}
+
+impl Visit for I18nextVisitor {
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        self.declare_t_function(node);
+        node.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Callee::Expr(callee) = &node.callee {
+            if let Some(t_function) = self.get_callee_t_function(callee) {
+                self.record_usage(t_function.get_translation_keys(&node.args), node.span);
+            }
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_jsx_element(&mut self, node: &JSXElement) {
+        match &node.opening.name {
+            JSXElementName::Ident(ident) if ident.sym == "Trans" => self.visit_trans(node),
+            _ => (),
+        }
+        node.visit_children_with(self);
+    }
+}
+
+// The keys of react-i18next and i18next `t` calls, attributed to the top level
+// symbols calling them. The keys are prefixed with their namespaces if any,
+// like `ns:key`.
+pub fn get_i18next_usage(module: &Module, module_path: &str, options: Options) -> TranslationUsage {
+    let mut wrapped_component_visitor = WrappedComponentVisitor::default();
+    module.visit_with(&mut wrapped_component_visitor);
+    let mut visitor = I18nextVisitor::new(
+        module_path,
+        wrapped_component_visitor.wrapped_components,
+        options,
+    );
+    // the top level `t` functions, the symbols only visit the initializers of
+    // their declarations
+    for module_item in &module.body {
+        match module_item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var_decl),
+                ..
+            })) => {
+                for var_declarator in &var_decl.decls {
+                    visitor.declare_t_function(var_declarator);
+                }
+            }
+            _ => (),
+        }
+    }
+    visitor.visit_top_level_symbols(module);
+
+    let mut usage = visitor.usage;
+    usage.symbols = visitor.scope.into_symbols(module);
+    // only the symbols using keys are reported
+    usage
+        .symbols
+        .retain(|name, _| usage.data.contains_key(name));
+    usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use std::collections::HashSet;
+
+    macro_rules! assert_i18next_usage {
+        ($input:expr, $($symbol_name:expr => [$($key:expr),* $(,)?]),* $(,)?) => {
+            let module = test_utils::parse_module($input).unwrap();
+            let usage = get_i18next_usage(&module, "test.js", Options::default()).data;
+            let expected: HashMap<String, HashSet<String>> = HashMap::from([
+                $(($symbol_name.to_string(), HashSet::from([$($key.to_string()),*]))),*
+            ]);
+            assert_eq!(usage, expected);
+        };
+    }
+
+    #[test]
+    fn use_translation() {
+        assert_i18next_usage!(
+            r#"
+            const Foo = () => {
+                const { t } = useTranslation()
+                return <div title={t("title")}>{t(["missing", "fallback"])}</div>
+            }
+            const Bar = () => {
+                const { t: translate } = useTranslation(["admin", "common"])
+                return <div>{translate("title")}{translate("common:ok")}</div>
+            }
+            function Baz() {
+                const [tBaz] = useTranslation("admin", { keyPrefix: "users.list" })
+                return tBaz("empty", { ns: "other" })
+            }
+            "#,
+            "Foo" => ["title", "missing", "fallback"],
+            "Bar" => ["admin:title", "common:ok"],
+            "Baz" => ["other:users.list.empty"],
+        );
+    }
+
+    #[test]
+    fn i18n_instance() {
+        assert_i18next_usage!(
+            r#"
+            i18n.t("boot")
+            export const format = () => i18next.t(`format.date`)
+            export default async function () {
+                const tFixed = await i18n.getFixedT(null, "mail", "footer")
+                return tFixed("unsubscribe")
+            }
+            "#,
+            "<module side effects>" => ["boot"],
+            "format" => ["format.date"],
+            "<default>" => ["mail:footer.unsubscribe"],
+        );
+    }
+
+    #[test]
+    fn top_level_symbols() {
+        assert_i18next_usage!(
+            r#"
+            const t = i18n.getFixedT(null, "mail")
+            exports.footer = () => t("unsubscribe")
+            namespace Admin {
+                export const Header = () => i18n.t("admin.title")
+            }
+            export = () => i18next.t("boot")
+            "#,
+            "module.exports.footer" => ["mail:unsubscribe"],
+            "Admin.Header" => ["admin.title"],
+            "<default>" => ["boot"],
+        );
+    }
+
+    #[test]
+    fn with_translation() {
+        assert_i18next_usage!(
+            r#"
+            function Foo({ t }) {
+                return t("title")
+            }
+            class Bar extends Component {
+                render() {
+                    return this.props.t("title")
+                }
+            }
+            const Baz = (props) => props.t("title")
+            const Qux = ({ t }) => t("title")
+            export const WrappedFoo = withTranslation()(Foo)
+            export const WrappedBar = withTranslation("admin")(Bar)
+            export default withTranslation("admin", { keyPrefix: "baz" })(Baz)
+            "#,
+            "Foo" => ["title"],
+            "Bar" => ["admin:title"],
+            "Baz" => ["admin:baz.title"],
+        );
+    }
+
+    #[test]
+    fn trans() {
+        let module = test_utils::parse_module(
+            r#"
+            const Foo = ({ name }) => (
+                <Trans i18nKey="welcome">
+                    Hello <b>{{ name }}</b>, you have {{ count, format: "number" }}
+                    {" "}messages.<br />
+                    {/* no child */}
+                    <strong>Read</strong> them <Link to="/inbox">here</Link>.
+                </Trans>
+            )
+            const Bar = () => <Trans i18nKey="admin:title" defaults="Hi <bold>{{name}}</bold>" />
+            function Baz() {
+                const { t } = useTranslation("users")
+                return <Trans t={t} ns="other">No key</Trans>
+            }
+            "#,
+        )
+        .unwrap();
+        let usage = get_i18next_usage(&module, "test.js", Options::default());
+        assert_eq!(
+            usage.get_default_texts("Foo", "welcome"),
+            [DefaultText {
+                text: "Hello <1>{{name}}</1>, you have {{count}} messages.<br/><strong>Read</strong> them <9>here</9>.".to_string(),
+                variables: vec!["name".to_string(), "count".to_string()],
+                components: vec!["1".to_string(), "br".to_string(), "strong".to_string(), "9".to_string()],
+            }]
+        );
+        assert_eq!(
+            usage.get_default_texts("Bar", "admin:title"),
+            [DefaultText::new("Hi <bold>{{name}}</bold>".to_string())]
+        );
+        assert_eq!(
+            usage.data["Baz"],
+            HashSet::from(["other:No key".to_string()])
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use crate::base_case_visitor::LabelsCollector;
+use crate::core::TranslationUsage;
+use crate::diagnostic::{Diagnostic, Rule};
+use crate::extractor::{Extractor, ExtractorContext};
+use crate::key_pattern::{
+    collect_string_constants, fold_string_expr, StringConstants, KEY_PATTERN_WILDCARD,
+};
+use anyhow::Context;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use swc_core::{
+    common::{Span, Spanned},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+// The rules the translation keys written in the code should follow, every rule
+// is optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyNamingConfig {
+    // a regex the whole key should match, like `^[a-z]+(\.[a-z_]+)+$`
+    pub pattern: Option<String>,
+    // directory -> the prefix of the keys used by the modules under it, like
+    // `{ "src/pet/": "pet." }`, the longest matching directory wins
+    pub prefixes: BTreeMap<String, String>,
+    // the max number of dot separated segments
+    pub max_depth: Option<usize>,
+    // `a.b_c.d1`, lowercase segments separated by dots
+    pub lowercase_dot_notation: bool,
+}
+
+impl KeyNamingConfig {
+    // The module paths may be absolute, so `src/pet/` matches both
+    // `src/pet/Pet.tsx` and `/root/app/src/pet/Pet.tsx`.
+    fn get_prefix(&self, module_path: &str) -> Option<&str> {
+        self.prefixes
+            .iter()
+            .filter_map(|(directory, prefix)| {
+                let directory = format!(
+                    "{}/",
+                    directory.trim_start_matches("./").trim_end_matches('/')
+                );
+                match module_path.starts_with(&directory)
+                    || module_path.contains(&format!("/{}", directory))
+                {
+                    true => Some((directory.len(), prefix.as_str())),
+                    false => None,
+                }
+            })
+            // `./src/a/` is less specific than `src/a/b`
+            .max_by_key(|(length, _)| *length)
+            .map(|(_, prefix)| prefix)
+    }
+}
+
+fn is_lowercase_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+struct KeyNamingVisitor<'a> {
+    config: &'a KeyNamingConfig,
+    pattern: Option<&'a Regex>,
+
+    // the prefix required by `KeyNamingConfig::prefixes` for this module
+    prefix: Option<&'a str>,
+
+    constants: StringConstants,
+
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl KeyNamingVisitor<'_> {
+    fn report(&mut self, message: String, span: Span) {
+        self.diagnostics
+            .push(Diagnostic::new(Rule::KeyNaming, message, span));
+    }
+
+    // `expr` is the key passed to `translate`, anything which isn't a string is
+    // left to the extractors.
+    fn check(&mut self, expr: &Expr) {
+        if let Some(key) = fold_string_expr(expr, &self.constants) {
+            self.check_key(&key, expr.span());
+        }
+    }
+
+    // `span` is where the key is written.
+    fn check_key(&mut self, key: &str, span: Span) {
+        // the parts which can't be evaluated can't be checked, `i18n.*.title`
+        // is only checked up to its wildcard for the prefix, and segment by
+        // segment for the notation
+        let known = match key.find(KEY_PATTERN_WILDCARD) {
+            Some(index) => &key[..index],
+            None => key,
+        };
+        let is_pattern = known.len() != key.len();
+        if let Some(pattern) = self.pattern {
+            if !is_pattern && !pattern.is_match(key) {
+                self.report(
+                    format!("\"{}\" doesn't match the pattern {}", key, pattern),
+                    span,
+                );
+            }
+        }
+        if let Some(prefix) = self.prefix {
+            let matches = match is_pattern {
+                true => known.starts_with(prefix) || prefix.starts_with(known),
+                false => key.starts_with(prefix),
+            };
+            if !matches {
+                self.report(
+                    format!(
+                        "\"{}\" should start with \"{}\" in this directory",
+                        key, prefix
+                    ),
+                    span,
+                );
+            }
+        }
+        if let Some(max_depth) = self.config.max_depth {
+            let depth = key.split('.').count();
+            if depth > max_depth {
+                self.report(
+                    format!(
+                        "\"{}\" is {} levels deep, the max depth is {}",
+                        key, depth, max_depth
+                    ),
+                    span,
+                );
+            }
+        }
+        if self.config.lowercase_dot_notation
+            && !key.split('.').all(|segment| {
+                segment.contains(KEY_PATTERN_WILDCARD) || is_lowercase_segment(segment)
+            })
+        {
+            self.report(format!("\"{}\" isn't in lowercase dot-notation", key), span);
+        }
+    }
+
+    // The keys of the labels of the object passed to `translate`, as the
+    // `LabelsCollector` collects them, the objects it fails to collect are
+    // reported by the extractors.
+    fn check_labels(&mut self, object_lit: &ObjectLit) {
+        let mut labels_collector = LabelsCollector::new(self.constants.clone());
+        if labels_collector.collect(object_lit).is_err() {
+            return;
+        }
+        for (key, span) in labels_collector.key_spans {
+            self.check_key(&key, span);
+        }
+    }
+}
+
+impl Visit for KeyNamingVisitor<'_> {
+    // `translate({ /* ... */ })` and `translate("<i18n key>")`
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Callee::Expr(expr) = &node.callee {
+            match &**expr {
+                Expr::Ident(ident) if ident.sym == "translate" => {
+                    if let Some(ExprOrSpread { spread: None, expr }) = node.args.first() {
+                        match &**expr {
+                            Expr::Object(object_lit) => self.check_labels(object_lit),
+                            expr => self.check(expr),
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        node.visit_children_with(self);
+    }
+}
+
+// Check the translation keys of the `translate` calls against the naming rules
+// of `KeyNamingConfig`, so the violations are fixed when the keys are written.
+// It only reports diagnostics, register it with `Analyzer::add_extractor`.
+pub struct KeyNamingLint {
+    config: KeyNamingConfig,
+    pattern: Option<Regex>,
+}
+
+impl KeyNamingLint {
+    pub fn new(config: KeyNamingConfig) -> anyhow::Result<Self> {
+        let pattern = match &config.pattern {
+            Some(pattern) => {
+                Some(Regex::new(pattern).context(format!("invalid key pattern {:?}", pattern))?)
+            }
+            None => None,
+        };
+        Ok(Self { config, pattern })
+    }
+}
+
+impl Extractor for KeyNamingLint {
+    fn extract(
+        &self,
+        module: &Module,
+        context: &ExtractorContext,
+    ) -> anyhow::Result<TranslationUsage> {
+        let mut visitor = KeyNamingVisitor {
+            config: &self.config,
+            pattern: self.pattern.as_ref(),
+            prefix: self.config.get_prefix(context.module_context.module_path),
+            constants: collect_string_constants(module),
+            diagnostics: vec![],
+        };
+        module.visit_with(&mut visitor);
+        let mut translation_usage = TranslationUsage::new();
+        translation_usage.diagnostics = visitor.diagnostics;
+        Ok(translation_usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{Analyzer, AnalyzerConfig};
+
+    #[test]
+    fn key_naming() {
+        let mut analyzer = Analyzer::new(AnalyzerConfig::default());
+        analyzer.add_extractor(
+            KeyNamingLint::new(KeyNamingConfig {
+                pattern: Some(r"^[a-z0-9_.*]+\.[a-z0-9_]+$".to_string()),
+                prefixes: BTreeMap::from([
+                    ("src/".to_string(), "app.".to_string()),
+                    ("./src/pet".to_string(), "pet.".to_string()),
+                    ("src/petshop/".to_string(), "petshop.".to_string()),
+                ]),
+                max_depth: Some(3),
+                lowercase_dot_notation: true,
+            })
+            .unwrap(),
+        );
+        let module_analysis = analyzer
+            .analyze_source(
+                "src/pet/Pet.tsx",
+                r#"const PREFIX = "pet.card";
+const LABELS = translate({
+    title: `${PREFIX}.title`,
+    name: "pet.cardName",
+    desc: ["pet.card.desc.long", "lazy"],
+    type: { cat: "app.cat" },
+    kind: `pet.${kind}.name`,
+});
+const MY_LABEL = translate("pet.Bird");
+export const Foo = () => <div>{LABELS.title}{MY_LABEL}</div>;
+"#,
+            )
+            .unwrap();
+        assert_eq!(
+            module_analysis
+                .diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.rule == Rule::KeyNaming)
+                .map(|diagnostic| (
+                    diagnostic.rule,
+                    diagnostic.location.line,
+                    diagnostic.message.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    Rule::KeyNaming,
+                    4,
+                    r#""pet.cardName" doesn't match the pattern ^[a-z0-9_.*]+\.[a-z0-9_]+$"#
+                ),
+                (
+                    Rule::KeyNaming,
+                    4,
+                    r#""pet.cardName" isn't in lowercase dot-notation"#
+                ),
+                (
+                    Rule::KeyNaming,
+                    5,
+                    r#""pet.card.desc.long" is 4 levels deep, the max depth is 3"#
+                ),
+                (
+                    Rule::KeyNaming,
+                    6,
+                    r#""app.cat" should start with "pet." in this directory"#
+                ),
+                (
+                    Rule::KeyNaming,
+                    9,
+                    r#""pet.Bird" doesn't match the pattern ^[a-z0-9_.*]+\.[a-z0-9_]+$"#
+                ),
+                (
+                    Rule::KeyNaming,
+                    9,
+                    r#""pet.Bird" isn't in lowercase dot-notation"#
+                ),
+            ]
+        );
+        // the most specific directory wins, however it's written
+        let config = KeyNamingConfig {
+            prefixes: BTreeMap::from([
+                ("./src/pet/".to_string(), "pet.".to_string()),
+                ("src/pet/card".to_string(), "pet.card.".to_string()),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.get_prefix("src/pet/card/Card.tsx"),
+            Some("pet.card.")
+        );
+        assert_eq!(config.get_prefix("/app/src/pet/Pet.tsx"), Some("pet."));
+        assert!(KeyNamingLint::new(KeyNamingConfig {
+            pattern: Some("[".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
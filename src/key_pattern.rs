@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use swc_core::ecma::ast::*;
+
+// Stands for the parts of a key we can't evaluate at build time, e.g.
+// `${type}` in `i18n.${type}.title` becomes `i18n.*.title`.
+pub const KEY_PATTERN_WILDCARD: char = '*';
+
+// Module scoped string constants, e.g. `const PREFIX = "i18n.pet"`.
+pub type StringConstants = HashMap<Id, String>;
+
+pub fn is_key_pattern(key: &str) -> bool {
+    key.contains(KEY_PATTERN_WILDCARD)
+}
+
+// Match a key pattern against a key from the Lokalise export, the wildcard
+// matches any sequence of characters, dots included.
+pub fn key_pattern_matches(pattern: &str, key: &str) -> bool {
+    let mut parts = pattern.split(KEY_PATTERN_WILDCARD);
+    let first = parts.next().unwrap_or_default();
+    if !key.starts_with(first) {
+        return false;
+    }
+    let mut rest = &key[first.len()..];
+    let mut parts: Vec<&str> = parts.collect();
+    let last = match parts.pop() {
+        Some(last) => last,
+        // no wildcard at all
+        None => return rest.is_empty(),
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+// A `*` written in the code can't be told apart from the wildcards of the
+// unknown parts, like `"i18n.*.title"` or `` `i18n.*.${type}` ``.
+pub fn has_literal_wildcard(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Str(Str { value, .. })) => value.contains(KEY_PATTERN_WILDCARD),
+        Expr::Tpl(tpl) => {
+            tpl.quasis
+                .iter()
+                .any(|quasi| quasi.raw.contains(KEY_PATTERN_WILDCARD))
+                || tpl.exprs.iter().any(|expr| has_literal_wildcard(expr))
+        }
+        Expr::Bin(BinExpr {
+            op: BinaryOp::Add,
+            left,
+            right,
+            ..
+        }) => has_literal_wildcard(left) || has_literal_wildcard(right),
+        Expr::Paren(ParenExpr { expr, .. }) => has_literal_wildcard(expr),
+        _ => false,
+    }
+}
+
+// Fold a string expression into a translation key. Template literals and
+// string concatenations are folded when all the parts are compile-time
+// constants, otherwise the unknown parts become wildcards.
+//
+// Returns `None` if the expression isn't a string at all, or if it writes a
+// `*` itself, see `has_literal_wildcard`.
+pub fn fold_string_expr(expr: &Expr, constants: &StringConstants) -> Option<String> {
+    if has_literal_wildcard(expr) {
+        return None;
+    }
+    let folded = match expr {
+        Expr::Lit(Lit::Str(Str { value, .. })) => value.to_string(),
+        Expr::Tpl(tpl) => {
+            let mut folded = String::new();
+            for (i, quasi) in tpl.quasis.iter().enumerate() {
+                match &quasi.cooked {
+                    Some(cooked) => folded.push_str(cooked),
+                    None => folded.push_str(&quasi.raw),
+                }
+                if let Some(expr) = tpl.exprs.get(i) {
+                    folded.push_str(&fold_operand(expr, constants));
+                }
+            }
+            folded
+        }
+        Expr::Bin(BinExpr {
+            op: BinaryOp::Add,
+            left,
+            right,
+            ..
+        }) => {
+            // `a + b` is only a string concatenation if one side is a string
+            let left_str = fold_string_expr(left, constants);
+            let right_str = fold_string_expr(right, constants);
+            if left_str.is_none() && right_str.is_none() {
+                return None;
+            }
+            format!(
+                "{}{}",
+                left_str.unwrap_or_else(|| fold_operand(left, constants)),
+                right_str.unwrap_or_else(|| fold_operand(right, constants))
+            )
+        }
+        Expr::Paren(ParenExpr { expr, .. }) => return fold_string_expr(expr, constants),
+        Expr::Ident(ident) => return constants.get(&ident.to_id()).cloned(),
+        _ => return None,
+    };
+    Some(collapse_wildcards(folded))
+}
+
+// Like `fold_string_expr`, but for the parts of a template literal or a
+// concatenation, where non-string constants are stringified and everything
+// else is unknown.
+fn fold_operand(expr: &Expr, constants: &StringConstants) -> String {
+    if let Some(folded) = fold_string_expr(expr, constants) {
+        return folded;
+    }
+    match expr {
+        Expr::Lit(Lit::Num(Number { value, .. })) => value.to_string(),
+        Expr::Lit(Lit::Bool(Bool { value, .. })) => value.to_string(),
+        Expr::Lit(Lit::Null(_)) => "null".to_string(),
+        Expr::Paren(ParenExpr { expr, .. }) => fold_operand(expr, constants),
+        _ => KEY_PATTERN_WILDCARD.to_string(),
+    }
+}
+
+fn collapse_wildcards(key: String) -> String {
+    let mut collapsed = String::with_capacity(key.len());
+    for c in key.chars() {
+        if c == KEY_PATTERN_WILDCARD && collapsed.ends_with(KEY_PATTERN_WILDCARD) {
+            continue;
+        }
+        collapsed.push(c);
+    }
+    collapsed
+}
+
+// Collect `const NAME = <string>` declared at module scope, so that keys like
+// `${PREFIX}.title` can be folded. Constants can refer to the ones declared
+// before them, and partially known strings are not treated as constants.
+pub fn collect_string_constants(module: &Module) -> StringConstants {
+    let mut constants = StringConstants::new();
+    for module_item in &module.body {
+        let var_decl = match module_item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var_decl),
+                ..
+            })) => var_decl,
+            _ => continue,
+        };
+        if var_decl.kind != VarDeclKind::Const {
+            continue;
+        }
+        for var_declarator in &var_decl.decls {
+            if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+                (&var_declarator.name, &var_declarator.init)
+            {
+                match fold_string_expr(init, &constants) {
+                    Some(value) if !is_key_pattern(&value) => {
+                        constants.insert(id.to_id(), value);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+    constants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    fn fold(input: &str) -> Option<String> {
+        let module = test_utils::parse_module(input).unwrap();
+        let constants = collect_string_constants(&module);
+        match module.body.last().unwrap() {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                fold_string_expr(expr, &constants)
+            }
+            _ => panic!("the last module item should be an expression"),
+        }
+    }
+
+    #[test]
+    fn string_literal() {
+        assert_eq!(fold(r#""i18n.bird""#).unwrap(), "i18n.bird");
+    }
+
+    #[test]
+    fn concatenation() {
+        assert_eq!(fold(r#""i18n." + "bird""#).unwrap(), "i18n.bird");
+        assert_eq!(
+            fold(r#"("i18n." + "bird") + ".size." + 2"#).unwrap(),
+            "i18n.bird.size.2"
+        );
+        assert_eq!(
+            fold(r#""i18n." + type + ".title""#).unwrap(),
+            "i18n.*.title"
+        );
+        assert_eq!(fold(r#"PREFIX + ".title""#).unwrap(), "*.title");
+    }
+
+    #[test]
+    fn template_literal() {
+        assert_eq!(fold("`i18n.bird`").unwrap(), "i18n.bird");
+        assert_eq!(fold("`i18n.${'bird'}.${1}`").unwrap(), "i18n.bird.1");
+        assert_eq!(fold("`i18n.${type}.title`").unwrap(), "i18n.*.title");
+        assert_eq!(fold("`i18n.${a}${b}.title`").unwrap(), "i18n.*.title");
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(
+            fold(
+                r#"
+                const PREFIX = "i18n";
+                export const PET = `${PREFIX}.pet`;
+                `${PET}.title`
+                "#
+            )
+            .unwrap(),
+            "i18n.pet.title"
+        );
+        assert_eq!(
+            fold(
+                r#"
+                let PREFIX = "i18n";
+                `${PREFIX}.title`
+                "#
+            )
+            .unwrap(),
+            "*.title"
+        );
+    }
+
+    #[test]
+    fn not_a_string() {
+        assert!(fold("1 + 2").is_none());
+        assert!(fold("a + b").is_none());
+        assert!(fold("PREFIX").is_none());
+        assert!(fold("translate('i18n.bird')").is_none());
+    }
+
+    #[test]
+    fn literal_wildcard() {
+        assert!(fold(r#""i18n.*.title""#).is_none());
+        assert!(fold("`i18n.*.${type}`").is_none());
+        assert!(fold(r#""i18n." + ("*" + type)"#).is_none());
+    }
+
+    #[test]
+    fn match_key_pattern() {
+        assert!(!is_key_pattern("i18n.bird.title"));
+        assert!(is_key_pattern("i18n.*.title"));
+
+        assert!(key_pattern_matches("i18n.bird", "i18n.bird"));
+        assert!(!key_pattern_matches("i18n.bird", "i18n.bird.title"));
+        assert!(key_pattern_matches("i18n.*.title", "i18n.bird.title"));
+        assert!(key_pattern_matches("i18n.*.title", "i18n.bird.small.title"));
+        assert!(!key_pattern_matches("i18n.*.title", "i18n.bird.desc"));
+        assert!(!key_pattern_matches("i18n.*.title", "i18n.title"));
+        assert!(key_pattern_matches("*.title", "i18n.title"));
+        assert!(key_pattern_matches("i18n.*", "i18n.bird"));
+        assert!(key_pattern_matches(
+            "i18n.*.*.title",
+            "i18n.bird.small.title"
+        ));
+        assert!(!key_pattern_matches("i18n.*.*.title", "i18n.bird.title"));
+    }
+}
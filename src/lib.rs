@@ -1,6 +1,17 @@
-mod anonymous_default_export;
+pub mod analyzer;
 mod base_case_visitor;
 pub mod core;
+pub mod diagnostic;
+pub mod extractor;
+pub mod fix;
+pub mod hard_coded_strings;
+mod i18next_visitor;
+pub mod key_naming;
+pub mod key_pattern;
+pub mod location;
+pub mod lokalise;
+mod react_intl_visitor;
+pub mod symbol;
 
 #[cfg(test)]
 mod test_utils;
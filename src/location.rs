@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use swc_core::common::{SourceMap, SourceMapper, Span};
+
+// Where a translation key is used, so reports and editors can point at it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Location {
+    pub file: String,
+    // 1-based
+    pub line: usize,
+    // 1-based, in characters
+    pub column: usize,
+    // the expression using the key, like `LABELS.a.b`
+    pub text: String,
+}
+
+impl Location {
+    // `source_map` is the one the module was parsed with.
+    pub fn new(source_map: &SourceMap, file: &str, span: Span) -> Self {
+        let loc = source_map.lookup_char_pos(span.lo);
+        Self {
+            file: file.to_string(),
+            line: loc.line,
+            column: loc.col.0 + 1,
+            text: source_map.span_to_snippet(span).unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use swc_core::common::Spanned;
+    use swc_core::ecma::ast::*;
+
+    #[test]
+    fn location_of_span() {
+        let (module, source_map) =
+            test_utils::parse_module_with_source_map("const a = 1\nconst é = LABELS.bird").unwrap();
+        let span = match &module.body[1] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                var_decl.decls[0].init.as_ref().unwrap().span()
+            }
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            Location::new(&source_map, "test.js", span),
+            Location {
+                file: "test.js".to_string(),
+                line: 2,
+                column: 11,
+                text: "LABELS.bird".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use crate::analyzer::ProjectAnalysis;
+use crate::location::Location;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+// The plural forms of a translation, `{ "one": "...", "other": "..." }`.
+const PLURAL_FORMS: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+// The translations of a single language exported from Lokalise as JSON, either
+// flat, `{ "lokalise.key.cat": "Cat" }`, or nested by the key separator,
+// `{ "lokalise": { "key": { "cat": "Cat" } } }`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LokaliseExport {
+    // translation key -> the translations, one for each plural form
+    translations: HashMap<String, Vec<String>>,
+}
+
+impl LokaliseExport {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path).context(format!("failed to load {:?}", path))?;
+        Self::from_json(&text).context(format!("failed to parse {:?}", path))
+    }
+
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        let value: Value = serde_json::from_str(text)?;
+        if !value.is_object() {
+            bail!("the export should be an object of translations");
+        }
+        let mut translations = HashMap::new();
+        let mut to_collect = vec![(String::new(), &value)];
+        while let Some((prefix, value)) = to_collect.pop() {
+            let join = |key: &str| match prefix.is_empty() {
+                true => key.to_string(),
+                false => format!("{}.{}", prefix, key),
+            };
+            match value {
+                Value::Object(map) if !prefix.is_empty() && is_plural(map) => {
+                    let forms = map
+                        .values()
+                        .filter_map(|form| form.as_str().map(|form| form.to_string()))
+                        .collect();
+                    translations.insert(prefix.clone(), forms);
+                }
+                Value::Object(map) => {
+                    for (key, value) in map {
+                        to_collect.push((join(key), value));
+                    }
+                }
+                Value::String(translation) => {
+                    translations.insert(prefix.clone(), vec![translation.clone()]);
+                }
+                _ => (),
+            }
+        }
+        Ok(Self { translations })
+    }
+
+    pub fn contains_key(&self, translation_key: &str) -> bool {
+        self.translations.contains_key(translation_key)
+    }
+
+    // The placeholders of all the plural forms of the key, None if the key
+    // isn't exported.
+    pub fn get_placeholders(&self, translation_key: &str) -> Option<BTreeSet<String>> {
+        let forms = self.translations.get(translation_key)?;
+        Some(
+            forms
+                .iter()
+                .flat_map(|translation| get_placeholders(translation))
+                .collect(),
+        )
+    }
+}
+
+fn is_plural(map: &serde_json::Map<String, Value>) -> bool {
+    !map.is_empty()
+        && map
+            .iter()
+            .all(|(form, value)| PLURAL_FORMS.contains(&form.as_str()) && value.is_string())
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+// The index of the `}` closing the `{` at `open`.
+fn find_closing_brace(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+// The arguments of an ICU message, `{date}`, `{date, date, short}` and
+// `{count, plural, one {# pet} other {# pets}}` whose branches are messages
+// too, only their own arguments are placeholders. i18next `{{date}}` is found
+// the same way.
+fn collect_icu_placeholders(message: &str, placeholders: &mut BTreeSet<String>) {
+    let mut from = 0;
+    while let Some(open) = message[from..].find('{').map(|i| from + i) {
+        let close = match find_closing_brace(message, open) {
+            Some(close) => close,
+            None => return,
+        };
+        let argument = &message[open + 1..close];
+        from = close + 1;
+        // `{{date}}`
+        if argument.starts_with('{') {
+            collect_icu_placeholders(argument, placeholders);
+            continue;
+        }
+        let mut parts = argument.splitn(3, ',');
+        let name = parts.next().unwrap_or_default().trim();
+        if is_placeholder_name(name) {
+            placeholders.insert(name.to_string());
+        }
+        let argument_type = parts.next().unwrap_or_default().trim();
+        if let ("plural" | "select" | "selectordinal", Some(branches)) =
+            (argument_type, parts.next())
+        {
+            // `one {# pet} other {# pets}`, the selectors aren't placeholders
+            let mut from = 0;
+            while let Some(open) = branches[from..].find('{').map(|i| from + i) {
+                let close = match find_closing_brace(branches, open) {
+                    Some(close) => close,
+                    None => break,
+                };
+                collect_icu_placeholders(&branches[open + 1..close], placeholders);
+                from = close + 1;
+            }
+        }
+    }
+}
+
+// The interpolation placeholders of a translation, ICU `{date}` and
+// `{count, plural, one {# pet} other {# pets}}`, i18next `{{date}}` and the
+// Lokalise universal placeholders `[%s:date]` or `[%1$s:date]`.
+pub fn get_placeholders(translation: &str) -> BTreeSet<String> {
+    let mut placeholders = BTreeSet::new();
+    collect_icu_placeholders(translation, &mut placeholders);
+    for (i, _) in translation.match_indices("[%") {
+        let rest = &translation[i + 2..];
+        let placeholder = match rest.find(']') {
+            Some(end) => &rest[..end],
+            None => continue,
+        };
+        if let Some((_, name)) = placeholder.split_once(':') {
+            if is_placeholder_name(name) {
+                placeholders.insert(name.to_string());
+            }
+        }
+    }
+    placeholders
+}
+
+// A call of a lazy label passing other parameters than the placeholders of its
+// translation, like `LABELS.desc({ day })` for `"Updated at {date}"`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParamsMismatch {
+    pub key: String,
+    pub location: Location,
+    // the placeholders without a parameter
+    pub missing: Vec<String>,
+    // the parameters without a placeholder
+    pub extra: Vec<String>,
+}
+
+// Compare the parameters of every call of the lazy labels with the
+// placeholders of their translations. The keys missing from the export and the
+// calls passing unknown parameters, like `LABELS.desc(params)`, are skipped.
+pub fn validate_params(
+    project_analysis: &ProjectAnalysis,
+    export: &LokaliseExport,
+) -> Vec<ParamsMismatch> {
+    let mut mismatches = vec![];
+    for module in &project_analysis.modules {
+        for symbol in &module.symbols {
+            for key in &symbol.keys {
+                if key.lazy.is_none() {
+                    continue;
+                }
+                let placeholders = match export.get_placeholders(&key.key) {
+                    Some(placeholders) => placeholders,
+                    None => continue,
+                };
+                for call in &key.calls {
+                    let params: BTreeSet<String> = match &call.params {
+                        Some(params) => params.iter().cloned().collect(),
+                        None => continue,
+                    };
+                    let missing: Vec<String> = placeholders.difference(&params).cloned().collect();
+                    let extra: Vec<String> = params.difference(&placeholders).cloned().collect();
+                    if missing.is_empty() && extra.is_empty() {
+                        continue;
+                    }
+                    mismatches.push(ParamsMismatch {
+                        key: key.key.clone(),
+                        location: call.location.clone(),
+                        missing,
+                        extra,
+                    });
+                }
+            }
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{Analyzer, AnalyzerConfig};
+
+    #[test]
+    fn placeholders() {
+        let placeholders = |translation: &str| -> Vec<String> {
+            get_placeholders(translation).into_iter().collect()
+        };
+        assert_eq!(placeholders("Updated at {date}"), ["date"]);
+        assert_eq!(
+            placeholders("{count, plural, one {# pet of {owner}} other {# pets}}"),
+            ["count", "owner"]
+        );
+        assert_eq!(
+            placeholders("{count, plural, one {pet} other {pets}}"),
+            ["count"]
+        );
+        assert_eq!(
+            placeholders("{gender, select, male {He} female {She} other {They}}"),
+            ["gender"]
+        );
+        assert_eq!(
+            placeholders(
+                "{rank, selectordinal, one {#st by {name}} other {#th}} on {date, date, short}"
+            ),
+            ["date", "name", "rank"]
+        );
+        assert_eq!(placeholders("Hello {{ name }}"), ["name"]);
+        assert_eq!(
+            placeholders("[%s:name] has [%1$i:count] pets"),
+            ["count", "name"]
+        );
+        assert!(placeholders("No {} placeholders [%s]").is_empty());
+    }
+
+    #[test]
+    fn export() {
+        let export = LokaliseExport::from_json(
+            r#"{
+                "i18n.title": "Title",
+                "i18n": {
+                    "desc": "Updated at {date}",
+                    "count": { "one": "[%s:count] pet", "other": "[%s:count] pets of {owner}" }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(export.contains_key("i18n.title"));
+        assert_eq!(
+            export.get_placeholders("i18n.desc").unwrap(),
+            BTreeSet::from(["date".to_string()])
+        );
+        assert_eq!(
+            export.get_placeholders("i18n.count").unwrap(),
+            BTreeSet::from(["count".to_string(), "owner".to_string()])
+        );
+        assert!(export.get_placeholders("i18n.missing").is_none());
+        assert!(LokaliseExport::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn params_mismatches() {
+        let analyzer = Analyzer::new(AnalyzerConfig::default());
+        let module_analysis = analyzer
+            .analyze_source(
+                "src/Foo.tsx",
+                r#"const LABELS = translate({
+    title: "i18n.title",
+    desc: ["i18n.desc", "lazy"],
+    count: ["i18n.count", "lazy"],
+});
+export const Foo = ({ date, params }) => <div>{LABELS.desc({ date })}{LABELS.desc({ day: date })}</div>;
+export const Bar = ({ params }) => <div>{LABELS.desc(params)}{LABELS.count({ count: 1 })}</div>;
+"#,
+            )
+            .unwrap();
+        let export = LokaliseExport::from_json(
+            r#"{ "i18n.desc": "Updated at {date}", "i18n.count": "{count} pets of {owner}" }"#,
+        )
+        .unwrap();
+        let mismatches = validate_params(
+            &ProjectAnalysis {
+                modules: vec![module_analysis],
+            },
+            &export,
+        );
+        assert_eq!(
+            mismatches
+                .iter()
+                .map(|mismatch| (
+                    mismatch.key.as_str(),
+                    mismatch.location.text.as_str(),
+                    mismatch.missing.clone(),
+                    mismatch.extra.clone(),
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "i18n.count",
+                    "LABELS.count({ count: 1 })",
+                    vec!["owner".to_string()],
+                    vec![],
+                ),
+                (
+                    "i18n.desc",
+                    "LABELS.desc({ day: date })",
+                    vec!["date".to_string()],
+                    vec!["day".to_string()],
+                ),
+            ]
+        );
+    }
+}
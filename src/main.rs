@@ -1,128 +1,106 @@
-use anyhow::{bail, Context};
-use lokalise_key_usage::core;
-use std::{
-    collections::{HashMap, HashSet},
-    path::{Path, PathBuf},
-};
-use swc_core::{
-    common::{sync::Lrc, Globals, Mark, SourceMap, GLOBALS},
-    ecma::{ast::*, transforms::base::resolver, visit::FoldWith},
-};
-use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
+use anyhow::Context;
+use lokalise_key_usage::analyzer::{Analyzer, AnalyzerConfig};
+use lokalise_key_usage::fix::fix_file;
+use lokalise_key_usage::hard_coded_strings::{HardCodedStringsConfig, HardCodedStringsLint};
+use lokalise_key_usage::key_naming::{KeyNamingConfig, KeyNamingLint};
+use lokalise_key_usage::lokalise::{validate_params, LokaliseExport};
+use std::path::Path;
 
-const PATH: &'static str = "./fixtures";
+const PATH: &str = "./fixtures";
 
-fn main() -> anyhow::Result<()> {
-    // {
-    //   "i18n.bird" => {
-    //     "module path 1" => ["A", "B"],
-    //     "module path 2" => ["Foo", "Bar"],
-    //   },
-    //   "i18n.cat" => {
-    //     "module path 1" => ["A", "B", "C"],
-    //   },
-    // }
-    let mut res: HashMap<String, HashMap<String, HashSet<String>>> = HashMap::new();
-    let all_paths = collect_all_paths(&PathBuf::from(PATH))?;
-    for path in all_paths.iter() {
-        let path_str = path.to_str().context("&PathBuf -> &str")?;
-        // - module path 1
-        //   {
-        //     "A" => ["i18n.bird", "i18n.cat"],
-        //     "B" => ["i18n.bird", "i18n.cat"]
-        //     "C" => ["i18n.cat"]
-        //   }
-        // - module path 2
-        //   {
-        //     "Foo" => ["i18n.bird"]
-        //     "Bar" => ["i18n.cat"]
-        //   }
-        let translate_usage = get_translation_usage(path)?;
-        for (symbol, translation_keys) in translate_usage {
-            for translation_key in translation_keys.iter() {
-                if !res.contains_key(translation_key) {
-                    res.insert(translation_key.to_owned(), HashMap::new());
-                }
-                if !res.get(translation_key).unwrap().contains_key(path_str) {
-                    res.get_mut(translation_key)
-                        .unwrap()
-                        .insert(path_str.to_string(), HashSet::new());
-                }
-                res.get_mut(translation_key)
-                    .unwrap()
-                    .get_mut(path_str)
-                    .unwrap()
-                    .insert(symbol.to_owned());
-            }
-        }
-    }
+// Also attribute the keys read by the components receiving a part of the
+// LABELS as props, see `AnalyzerConfig::follow_props`.
+const FOLLOW_PROPS_FLAG: &str = "--follow-props";
 
-    println!("{:#?}", res);
+// Report the members of classes and objects as their own symbols, like
+// `Foo#handleClick`, see `AnalyzerConfig::qualified_symbols`.
+const QUALIFIED_SYMBOLS_FLAG: &str = "--qualified-symbols";
 
-    Ok(())
-}
+// Also extract the keys of react-i18next and i18next `t` calls, see
+// `AnalyzerConfig::i18next`.
+const I18NEXT_FLAG: &str = "--i18next";
+
+// Also extract the message ids of react-intl, see `AnalyzerConfig::react_intl`.
+const REACT_INTL_FLAG: &str = "--react-intl";
 
-fn collect_all_paths(root: &PathBuf) -> anyhow::Result<Vec<PathBuf>> {
-    let path = root.canonicalize()?;
-    let mut paths = vec![];
+// `--lokalise-export=<path>` validates the parameters of the lazy labels
+// against the placeholders of the translations of a Lokalise JSON export.
+const LOKALISE_EXPORT_FLAG: &str = "--lokalise-export=";
 
-    if path.is_dir() {
-        for entry in path.read_dir()? {
-            if let Ok(entry) = entry {
-                paths.append(&mut collect_all_paths(&entry.path())?);
+// Report the user-facing strings written in JSX instead of being translated,
+// `--allowlist=<path>` lists the strings allowed, one per line.
+const LINT_HARD_CODED_STRINGS_FLAG: &str = "--lint-hard-coded-strings";
+const ALLOWLIST_FLAG: &str = "--allowlist=";
+
+// `--key-naming=<path>` checks the keys of the `translate` calls against the
+// naming rules of a JSON config, see `KeyNamingConfig`.
+const KEY_NAMING_FLAG: &str = "--key-naming=";
+
+// Rewrite the modules to remove the LABELS properties they never use.
+const FIX_FLAG: &str = "--fix";
+
+fn get_flag_value(flag: &str) -> Option<String> {
+    std::env::args().find_map(|arg| arg.strip_prefix(flag).map(|value| value.to_string()))
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut analyzer = Analyzer::new(AnalyzerConfig {
+        follow_props: std::env::args().any(|arg| arg == FOLLOW_PROPS_FLAG),
+        qualified_symbols: std::env::args().any(|arg| arg == QUALIFIED_SYMBOLS_FLAG),
+        i18next: std::env::args().any(|arg| arg == I18NEXT_FLAG),
+        react_intl: std::env::args().any(|arg| arg == REACT_INTL_FLAG),
+    });
+    if std::env::args().any(|arg| arg == LINT_HARD_CODED_STRINGS_FLAG) {
+        let mut config = HardCodedStringsConfig::default();
+        if let Some(allowlist_path) = get_flag_value(ALLOWLIST_FLAG) {
+            let allowlist = std::fs::read_to_string(&allowlist_path)
+                .context(format!("failed to load {:?}", allowlist_path))?;
+            config.allowlist = allowlist
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect();
+        }
+        analyzer.add_extractor(HardCodedStringsLint::new(config));
+    }
+    if let Some(config_path) = get_flag_value(KEY_NAMING_FLAG) {
+        let config = std::fs::read_to_string(&config_path)
+            .context(format!("failed to load {:?}", config_path))?;
+        let config: KeyNamingConfig =
+            serde_json::from_str(&config).context(format!("failed to parse {:?}", config_path))?;
+        analyzer.add_extractor(KeyNamingLint::new(config)?);
+    }
+    let project_analysis = analyzer.analyze_project(Path::new(PATH))?;
+    println!("{}", serde_json::to_string_pretty(&project_analysis)?);
+    for module in &project_analysis.modules {
+        for diagnostic in &module.diagnostics {
+            eprintln!("{}", diagnostic);
+        }
+    }
+    if std::env::args().any(|arg| arg == FIX_FLAG) {
+        for module in &project_analysis.modules {
+            let fixed_count = fix_file(Path::new(&module.path), &module.diagnostics)?;
+            if fixed_count > 0 {
+                eprintln!("{}: fixed {} problems", module.path, fixed_count);
             }
         }
-        return Ok(paths);
     }
 
-    let path_str = path.to_str().context("path to str")?;
-    if path_str.ends_with(".js")
-        || path_str.ends_with(".jsx")
-        || path_str.ends_with(".ts")
-        || path_str.ends_with(".tsx")
-    {
-        if !path_str.ends_with(".spec.js")
-            && !path_str.ends_with(".spec.jsx")
-            && !path_str.ends_with(".spec.ts")
-            && !path_str.ends_with(".spec.tsx")
-            && !path_str.ends_with(".test.js")
-            && !path_str.ends_with(".test.jsx")
-            && !path_str.ends_with(".test.ts")
-            && !path_str.ends_with(".test.tsx")
-        {
-            paths.push(path.clone())
+    if let Some(export_path) = get_flag_value(LOKALISE_EXPORT_FLAG) {
+        let export = LokaliseExport::load(Path::new(&export_path))?;
+        for mismatch in validate_params(&project_analysis, &export) {
+            eprintln!(
+                "{}:{}:{}: {} is called with {}, missing: [{}], extra: [{}]",
+                mismatch.location.file,
+                mismatch.location.line,
+                mismatch.location.column,
+                mismatch.key,
+                mismatch.location.text,
+                mismatch.missing.join(", "),
+                mismatch.extra.join(", "),
+            );
         }
     }
-    Ok(paths)
-}
-
-fn get_translation_usage(path: &PathBuf) -> anyhow::Result<HashMap<String, HashSet<String>>> {
-    let cm: Lrc<SourceMap> = Default::default();
-    let fm = cm
-        .load_file(Path::new(path))
-        .context(format!("failed to load {:?}", path))?;
-
-    let module = match parse_file_as_module(
-        &fm,
-        Syntax::Typescript(TsSyntax {
-            tsx: true,
-            decorators: true,
-            no_early_errors: true,
-            ..Default::default()
-        }),
-        EsVersion::latest(),
-        None,
-        &mut Vec::new(),
-    ) {
-        Ok(v) => v,
-        // We are not testing parser
-        Err(..) => bail!("failed to parse {:?}", path),
-    };
 
-    // This is how swc manages identifiers. ref: https://rustdoc.swc.rs/swc_ecma_transforms/fn.resolver.html
-    let module = GLOBALS.set(&Globals::new(), move || {
-        module.fold_with(&mut resolver(Mark::new(), Mark::new(), true))
-    });
-
-    Ok(core::collect_translation(&module)?)
+    Ok(())
 }
@@ -0,0 +1,344 @@
+use crate::base_case_visitor::{
+    get_prop_name, PathSegment, SymbolScope, SymbolVisitor, TranslateObjectValue, LABELS,
+};
+use crate::core::{Options, TranslationUsage};
+use crate::key_pattern::{collect_string_constants, fold_string_expr, StringConstants};
+use std::collections::HashMap;
+use swc_core::{
+    common::{Span, Spanned},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+// `<FormattedMessage id="..." />`
+const FORMATTED_MESSAGE_COMPONENTS: [&str; 2] = ["FormattedMessage", "FormattedHTMLMessage"];
+
+// `{ id: "app.greeting", defaultMessage: "Hello", description: "..." }` -> "app.greeting"
+fn get_message_id(expr: &Expr, constants: &StringConstants) -> Option<String> {
+    match expr {
+        Expr::Object(ObjectLit { props, .. }) => props.iter().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(KeyValueProp { key, value }) => match get_prop_name(key)? == "id" {
+                    true => fold_string_expr(value, constants),
+                    false => None,
+                },
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        }),
+        _ => None,
+    }
+}
+
+// The message descriptors of `defineMessages({ /* ... */ })` as a `LABELS`
+// tree, `{ greeting: { id: "app.greeting" } }` is `{ greeting: "app.greeting" }`.
+// The descriptors without an id are skipped.
+fn get_messages(object_lit: &ObjectLit, constants: &StringConstants) -> LABELS {
+    let mut messages = HashMap::new();
+    for prop in &object_lit.props {
+        match prop {
+            PropOrSpread::Prop(prop) => {
+                if let Prop::KeyValue(KeyValueProp { key, value }) = &**prop {
+                    if let (Some(name), Some(id)) =
+                        (get_prop_name(key), get_message_id(value, constants))
+                    {
+                        messages.insert(name, TranslateObjectValue::String(id));
+                    }
+                }
+            }
+            PropOrSpread::Spread(_) => (),
+        }
+    }
+    LABELS::Object(messages)
+}
+
+// `const messages = defineMessages({ /* ... */ })`
+fn get_define_messages_arg(expr: &Expr) -> Option<&ObjectLit> {
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => match &**callee {
+            Expr::Ident(ident) if ident.sym == "defineMessages" => match args.first() {
+                Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+                    Expr::Object(object_lit) => Some(object_lit),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Like the `LABELS`, only the module scoped messages are collected.
+fn collect_messages(module: &Module, constants: &StringConstants) -> HashMap<Id, LABELS> {
+    let mut messages = HashMap::new();
+    for module_item in &module.body {
+        let var_decl = match module_item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var_decl),
+                ..
+            })) => var_decl,
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl,
+            _ => continue,
+        };
+        for var_declarator in &var_decl.decls {
+            if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+                (&var_declarator.name, var_declarator.init.as_deref())
+            {
+                if let Some(object_lit) = get_define_messages_arg(init) {
+                    messages.insert(id.to_id(), get_messages(object_lit, constants));
+                }
+            }
+        }
+    }
+    messages
+}
+
+// `intl.formatMessage(descriptor)`, `formatMessage(descriptor)` with
+// `const { formatMessage } = useIntl()`
+fn is_format_message(callee: &Expr) -> bool {
+    match callee {
+        Expr::Ident(ident) => ident.sym == "formatMessage",
+        Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => prop.sym == "formatMessage",
+        _ => false,
+    }
+}
+
+struct ReactIntlVisitor {
+    // the top level symbol being visited
+    scope: SymbolScope,
+
+    // `const messages = defineMessages({ /* ... */ })` -> the messages
+    messages: HashMap<Id, LABELS>,
+
+    // module scoped string constants, used to fold ids like `${PREFIX}.title`
+    constants: StringConstants,
+
+    usage: TranslationUsage,
+}
+
+impl ReactIntlVisitor {
+    fn new(module_path: &str, module: &Module, options: Options) -> Self {
+        let constants = collect_string_constants(module);
+        Self {
+            scope: SymbolScope::new(module_path, options),
+            messages: collect_messages(module, &constants),
+            constants,
+            usage: TranslationUsage::new(),
+        }
+    }
+
+    fn record_usage(&mut self, translation_keys: Vec<String>, span: Span) {
+        let symbol = match self.scope.get_current_symbol() {
+            Some(symbol) => symbol,
+            None => return,
+        };
+        for translation_key in translation_keys {
+            self.usage
+                .data
+                .entry(symbol.clone())
+                .or_default()
+                .insert(translation_key.clone());
+            let spans = self
+                .usage
+                .occurrences
+                .entry(symbol.clone())
+                .or_default()
+                .entry(translation_key)
+                .or_default();
+            if !spans.contains(&span) {
+                spans.push(span);
+            }
+        }
+    }
+
+    // `messages.greeting` or `messages[type]` -> the ids it could be
+    fn get_message_ids(&self, member_expr: &MemberExpr) -> Option<Vec<String>> {
+        let mut path = vec![PathSegment::from_member_prop(&member_expr.prop).ok()?];
+        let mut obj: &Expr = &member_expr.obj;
+        loop {
+            match obj {
+                Expr::Member(member_expr) => {
+                    path.push(PathSegment::from_member_prop(&member_expr.prop).ok()?);
+                    obj = &member_expr.obj;
+                }
+                Expr::Ident(ident) => {
+                    let messages = self.messages.get(&ident.to_id())?;
+                    path.reverse();
+                    let ids = messages.get_translation_keys_for_path(&path).ok()?;
+                    return Some(ids.into_iter().collect());
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+impl SymbolVisitor for ReactIntlVisitor {
+    fn symbol_scope(&mut self) -> &mut SymbolScope {
+        &mut self.scope
+    }
+}
+
+impl Visit for ReactIntlVisitor {
+    // `intl.formatMessage(messages.greeting)` or `<FormattedMessage {...messages.greeting} />`
+    fn visit_member_expr(&mut self, node: &MemberExpr) {
+        match self.get_message_ids(node) {
+            Some(ids) => {
+                self.record_usage(ids, node.span);
+                // `messages[type]`, the shorter `messages.a` of `messages.a.b`
+                // isn't another usage
+                let mut member_expr = node;
+                loop {
+                    if let MemberProp::Computed(computed) = &member_expr.prop {
+                        computed.visit_with(self);
+                    }
+                    match &*member_expr.obj {
+                        Expr::Member(obj) => member_expr = obj,
+                        _ => break,
+                    }
+                }
+            }
+            None => node.visit_children_with(self),
+        }
+    }
+
+    // `<FormattedMessage id="app.greeting" defaultMessage="Hello" />`
+    fn visit_jsx_opening_element(&mut self, node: &JSXOpeningElement) {
+        match &node.name {
+            JSXElementName::Ident(ident) if FORMATTED_MESSAGE_COMPONENTS.contains(&&*ident.sym) => {
+                for attr in &node.attrs {
+                    match attr {
+                        JSXAttrOrSpread::JSXAttr(JSXAttr {
+                            name: JSXAttrName::Ident(name),
+                            value: Some(value),
+                            ..
+                        }) if name.sym == "id" => {
+                            let id = match value {
+                                JSXAttrValue::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+                                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                    expr: JSXExpr::Expr(expr),
+                                    ..
+                                }) => fold_string_expr(expr, &self.constants),
+                                _ => None,
+                            };
+                            if let Some(id) = id {
+                                self.record_usage(vec![id], value.span());
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+        node.visit_children_with(self);
+    }
+
+    // `intl.formatMessage({ id: "app.greeting", defaultMessage: "Hello" })`
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        match &node.callee {
+            Callee::Expr(callee) if is_format_message(callee) => {
+                if let Some(ExprOrSpread { spread: None, expr }) = node.args.first() {
+                    if let Some(id) = get_message_id(expr, &self.constants) {
+                        self.record_usage(vec![id], expr.span());
+                    }
+                }
+            }
+            _ => (),
+        }
+        node.visit_children_with(self);
+    }
+}
+
+// The message ids of react-intl, `defineMessages` read as `messages.greeting`,
+// `<FormattedMessage id="..." />` and `intl.formatMessage({ id: "..." })`,
+// attributed to the top level symbols using them. Only the messages defined in
+// the module are known.
+pub fn get_react_intl_usage(
+    module: &Module,
+    module_path: &str,
+    options: Options,
+) -> TranslationUsage {
+    let mut visitor = ReactIntlVisitor::new(module_path, module, options);
+    visitor.visit_top_level_symbols(module);
+
+    let mut usage = visitor.usage;
+    usage.symbols = visitor.scope.into_symbols(module);
+    // only the symbols using keys are reported
+    usage
+        .symbols
+        .retain(|name, _| usage.data.contains_key(name));
+    usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use std::collections::HashSet;
+
+    macro_rules! assert_react_intl_usage {
+        ($input:expr, $($symbol_name:expr => [$($key:expr),* $(,)?]),* $(,)?) => {
+            let module = test_utils::parse_module($input).unwrap();
+            let usage = get_react_intl_usage(&module, "test.js", Options::default()).data;
+            let expected: HashMap<String, HashSet<String>> = HashMap::from([
+                $(($symbol_name.to_string(), HashSet::from([$($key.to_string()),*]))),*
+            ]);
+            assert_eq!(usage, expected);
+        };
+    }
+
+    #[test]
+    fn define_messages() {
+        assert_react_intl_usage!(
+            r#"
+            const PREFIX = "app.pets"
+            const messages = defineMessages({
+                cat: { id: `${PREFIX}.cat`, defaultMessage: "Cat" },
+                dog: { id: "app.pets.dog", defaultMessage: "Dog", description: "a dog" },
+                broken: { defaultMessage: "No id" },
+            })
+            const Foo = () => {
+                const intl = useIntl()
+                return <div title={intl.formatMessage(messages.cat)} />
+            }
+            const Bar = () => <FormattedMessage {...messages.dog} />
+            export default function ({ type }) {
+                return intl.formatMessage(messages[type], { count: 1 })
+            }
+            "#,
+            "Foo" => ["app.pets.cat"],
+            "Bar" => ["app.pets.dog"],
+            "<default>" => ["app.pets.cat", "app.pets.dog"],
+        );
+    }
+
+    #[test]
+    fn inline_descriptors() {
+        assert_react_intl_usage!(
+            r#"
+            const Foo = () => <FormattedMessage id="app.title" defaultMessage="Title" />
+            const Bar = () => <FormattedMessage id={"app.subtitle"} />
+            function Baz() {
+                const { formatMessage } = useIntl()
+                return formatMessage({ id: "app.baz", defaultMessage: "Baz" })
+            }
+            toast(intl.formatMessage({ id: "app.boot" }))
+            "#,
+            "Foo" => ["app.title"],
+            "Bar" => ["app.subtitle"],
+            "Baz" => ["app.baz"],
+            "<module side effects>" => ["app.boot"],
+        );
+    }
+}
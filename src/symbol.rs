@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use swc_core::common::Span;
+
+// The name of an anonymous default export. It isn't an identifier, so unlike
+// `default` it can't be the name of another symbol, e.g. the one of
+// `exports.default = () => { /* ... */ }`. It's exported as `default`.
+pub const ANONYMOUS_DEFAULT_EXPORT_NAME: &str = "<default>";
+
+// The top level statements which aren't declarations, like
+// `registerToast(LABELS.saved)` or `if (DEBUG) { /* ... */ }`, run when the
+// module is imported, so they are reported under this symbol.
+pub const MODULE_SIDE_EFFECTS_NAME: &str = "<module side effects>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Function,
+    Class,
+    Variable,
+    Enum,
+    // export default () => { /* ... */ }
+    Default,
+    ModuleSideEffect,
+}
+
+// A symbol of a module using the LABELS.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    // the name the usage is keyed by, like `Foo`, `Foo#handleClick` or
+    // `<default>` for an anonymous default export
+    pub name: String,
+    // what to show the users, an anonymous default export is named after its
+    // module, like `Button` for `Button.tsx` or `Button/index.tsx`
+    pub display_name: String,
+    pub kind: SymbolKind,
+    // the names it's exported as, `default` included
+    pub exported_as: Vec<String>,
+    pub span: Span,
+}
+
+impl Symbol {
+    pub fn new(name: String, kind: SymbolKind, span: Span) -> Self {
+        Self {
+            display_name: name.clone(),
+            name,
+            kind,
+            exported_as: vec![],
+            span,
+        }
+    }
+}
+
+pub fn get_display_name_for_module(module_path: &str) -> String {
+    let path = Path::new(module_path);
+    let stem = path.file_stem().and_then(|stem| stem.to_str());
+    let name = match stem {
+        Some("index") => path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .and_then(|name| name.to_str()),
+        stem => stem,
+    };
+    match name {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => "default".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_name_for_module() {
+        assert_eq!(get_display_name_for_module("src/Button.tsx"), "Button");
+        assert_eq!(
+            get_display_name_for_module("src/Button/index.tsx"),
+            "Button"
+        );
+        assert_eq!(get_display_name_for_module("Button.test.js"), "Button.test");
+        assert_eq!(get_display_name_for_module(""), "default");
+    }
+}
@@ -6,6 +6,10 @@ use swc_core::{
 use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
 
 pub fn parse_module(input: &str) -> anyhow::Result<Module> {
+    Ok(parse_module_with_source_map(input)?.0)
+}
+
+pub fn parse_module_with_source_map(input: &str) -> anyhow::Result<(Module, Lrc<SourceMap>)> {
     let cm: Lrc<SourceMap> = Default::default();
     let fm = cm.new_source_file(Lrc::new(FileName::Custom("test.js".into())), input.into());
     match parse_file_as_module(
@@ -20,7 +24,7 @@ pub fn parse_module(input: &str) -> anyhow::Result<Module> {
         None,
         &mut Vec::new(),
     ) {
-        Ok(module) => Ok(module),
+        Ok(module) => Ok((module, cm)),
         Err(_) => bail!("failed to parse module"),
     }
 }